@@ -200,6 +200,7 @@ where
 	/// the new length of the map exceeds `S`.
 	///
 	/// In the `Err` case, returns the inserted pair so it can be further used without cloning.
+	/// Replacing the value of an existing key always succeeds, even when the map is full.
 	pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
 		if self.len() < Self::bound() || self.0.contains_key(&key) {
 			Ok(self.0.insert(key, value))
@@ -514,6 +515,21 @@ mod test {
 		assert_eq!(*bounded, map_from_keys(&[1, 0, 2, 3]));
 	}
 
+	#[test]
+	fn try_insert_overwrites_at_capacity() {
+		let mut bounded = BoundedBTreeMap::<u32, u32, ConstU32<2>>::new();
+		assert_eq!(bounded.try_insert(1, 10), Ok(None));
+		assert_eq!(bounded.try_insert(2, 20), Ok(None));
+
+		// existing key at capacity: replaced, old value returned.
+		assert_eq!(bounded.try_insert(1, 11), Ok(Some(10)));
+		// new key at capacity: rejected pair returned, map unchanged.
+		assert_eq!(bounded.try_insert(3, 30), Err((3, 30)));
+		assert_eq!(bounded.len(), 2);
+		assert_eq!(bounded.get(&1), Some(&11));
+		assert_eq!(bounded.get(&3), None);
+	}
+
 	#[test]
 	fn deref_coercion_works() {
 		let bounded = boundedmap_from_keys::<u32, ConstU32<7>>(&[1, 2, 3]);
@@ -392,12 +392,17 @@ where
 {
 	type Error = &'static str;
 
+	/// Duplicate items are only counted once towards the bound.
 	fn try_collect(self) -> Result<BoundedBTreeSet<T, Bound>, Self::Error> {
-		if self.len() > Bound::get() as usize {
-			Err("iterator length too big")
-		} else {
-			Ok(BoundedBTreeSet::<T, Bound>::unchecked_from(self.collect::<BTreeSet<T>>()))
+		let max = Bound::get() as usize;
+		let mut set = BTreeSet::new();
+		for item in self {
+			set.insert(item);
+			if set.len() > max {
+				return Err("iterator length too big")
+			}
 		}
+		Ok(BoundedBTreeSet::<T, Bound>::unchecked_from(set))
 	}
 }
 
@@ -579,6 +584,40 @@ mod test {
 		assert!(b2.is_err());
 	}
 
+	#[test]
+	fn try_collect_counts_duplicates_once() {
+		let b: BoundedBTreeSet<u32, ConstU32<2>> = vec![1, 2, 1, 2, 2].into_iter().try_collect().unwrap();
+		assert_eq!(b.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+
+		let b: Result<BoundedBTreeSet<u32, ConstU32<2>>, _> = vec![1, 2, 1, 3].into_iter().try_collect();
+		assert!(b.is_err());
+	}
+
+	#[test]
+	fn try_collect_stops_at_the_bound() {
+		let mut consumed = 0;
+		let b: Result<BoundedBTreeSet<u32, ConstU32<3>>, _> = (0..100).inspect(|_| consumed += 1).try_collect();
+		assert!(b.is_err());
+		assert_eq!(consumed, 4);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn bounded_btree_set_macro_works() {
+		let b: BoundedBTreeSet<u32, ConstU32<3>> = crate::bounded_btree_set![3, 1, 2, 1];
+		assert_eq!(b.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+		let b: BoundedBTreeSet<u32, ConstU32<3>> = crate::bounded_btree_set![];
+		assert!(b.is_empty());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	#[should_panic]
+	fn bounded_btree_set_macro_panics_over_bound() {
+		let _: BoundedBTreeSet<u32, ConstU32<2>> = crate::bounded_btree_set![1, 2, 3];
+	}
+
 	// Just a test that structs containing `BoundedBTreeSet` can derive `Hash`. (This was broken
 	// when it was deriving `Hash`).
 	#[test]
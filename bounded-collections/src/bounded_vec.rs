@@ -404,6 +404,18 @@ impl<T, S> BoundedVec<T, S> {
 	{
 		self.0.drain(range)
 	}
+
+	/// Exactly the same semantics as [`Vec::split_off`].
+	///
+	/// This is safe since both halves hold at most as many elements as the original vector, so
+	/// the returned vector satisfies the same bound.
+	///
+	/// # Panics
+	///
+	/// Panics if `at > len`.
+	pub fn split_off(&mut self, at: usize) -> Self {
+		Self::unchecked_from(self.0.split_off(at))
+	}
 }
 
 impl<T, S: Get<u32>> From<BoundedVec<T, S>> for Vec<T> {
@@ -1085,6 +1097,30 @@ mod test {
 		assert!(bounded.try_push(9).is_err());
 	}
 
+	#[test]
+	fn try_insert_at_any_position_works() {
+		let mut bounded: BoundedVec<u32, ConstU32<5>> = bounded_vec![2, 4];
+		// front
+		assert_eq!(bounded.try_insert(0, 1), Ok(()));
+		// middle
+		assert_eq!(bounded.try_insert(2, 3), Ok(()));
+		// end
+		assert_eq!(bounded.try_insert(4, 5), Ok(()));
+		assert_eq!(*bounded, vec![1, 2, 3, 4, 5]);
+
+		// at capacity the element is handed back and nothing is shifted.
+		assert_eq!(bounded.try_insert(0, 0), Err(0));
+		assert_eq!(bounded.try_insert(5, 6), Err(6));
+		assert_eq!(*bounded, vec![1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	#[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+	fn try_insert_panics_out_of_bounds() {
+		let mut bounded: BoundedVec<u32, ConstU32<5>> = bounded_vec![1, 2];
+		let _ = bounded.try_insert(3, 3);
+	}
+
 	#[test]
 	fn deref_vec_coercion_works() {
 		let bounded: BoundedVec<u32, ConstU32<7>> = bounded_vec![1, 2, 3];
@@ -1355,6 +1391,29 @@ mod test {
 		assert_eq!(bound, o);
 	}
 
+	#[test]
+	fn bounded_vec_split_off_works() {
+		let mut bound = BoundedVec::<u32, ConstU32<4>>::truncate_from(vec![1, 2, 3, 4]);
+		bound.try_rotate_left(1).unwrap();
+
+		let tail = bound.split_off(3);
+		assert_eq!(bound, vec![2, 3, 4]);
+		assert_eq!(tail, vec![1]);
+		assert!(tail.len() <= BoundedVec::<u32, ConstU32<4>>::bound());
+
+		let mut all = bound.split_off(0);
+		assert!(bound.is_empty());
+		assert_eq!(all, vec![2, 3, 4]);
+		assert!(all.split_off(3).is_empty());
+	}
+
+	#[test]
+	#[should_panic]
+	fn bounded_vec_split_off_out_of_bounds_panics() {
+		let mut bound = BoundedVec::<u32, ConstU32<4>>::truncate_from(vec![1, 2]);
+		bound.split_off(3);
+	}
+
 	// Just a test that structs containing `BoundedVec` and `BoundedSlice` can derive `Hash`. (This was broken when
 	// they were deriving `Hash`).
 	#[test]
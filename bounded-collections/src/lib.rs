@@ -17,6 +17,7 @@ pub mod bounded_btree_map;
 pub mod bounded_btree_set;
 pub mod bounded_vec;
 pub mod const_int;
+pub mod ring_buffer;
 pub mod weak_bounded_vec;
 
 mod test;
@@ -25,6 +26,7 @@ pub use bounded_btree_map::BoundedBTreeMap;
 pub use bounded_btree_set::BoundedBTreeSet;
 pub use bounded_vec::{BoundedSlice, BoundedVec};
 pub use const_int::{ConstInt, ConstUint};
+pub use ring_buffer::RingBuffer;
 pub use weak_bounded_vec::WeakBoundedVec;
 
 /// A trait for querying a single value from a type defined in the trait.
@@ -275,3 +277,21 @@ macro_rules! bounded_btree_map {
 		}
 	};
 }
+
+/// Build a bounded btree-set from the given literals.
+///
+/// The type of the outcome must be known.
+///
+/// Will not handle any errors and just panic if the given literals cannot fit in the corresponding
+/// bounded set type. Thus, this is only suitable for testing and non-consensus code.
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! bounded_btree_set {
+	($ ( $value:expr ),* $(,)?) => {
+		{
+			$crate::TryCollect::<$crate::BoundedBTreeSet<_, _>>::try_collect(
+				$crate::alloc::vec![$($value),*].into_iter()
+			).unwrap()
+		}
+	};
+}
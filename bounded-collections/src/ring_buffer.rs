@@ -0,0 +1,159 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Traits, types and structs to support a bounded sliding window of the most recent items.
+
+use crate::Get;
+use alloc::collections::VecDeque;
+use core::marker::PhantomData;
+
+/// A bounded buffer keeping the `S::get()` most recently pushed items.
+///
+/// Unlike [`BoundedVec`](crate::BoundedVec), pushing into a full buffer never fails: the oldest
+/// item is evicted instead.
+pub struct RingBuffer<T, S>(VecDeque<T>, PhantomData<S>);
+
+impl<T, S> RingBuffer<T, S>
+where
+	S: Get<u32>,
+{
+	/// Get the bound of the type in `usize`.
+	pub fn bound() -> usize {
+		S::get() as usize
+	}
+
+	/// Create a new, empty `RingBuffer`.
+	pub fn new() -> Self {
+		RingBuffer(VecDeque::new(), PhantomData)
+	}
+
+	/// Push `item` as the most recent item, returning the oldest item if it had to be evicted.
+	///
+	/// If the bound is zero, `item` is handed back straight away.
+	pub fn push(&mut self, item: T) -> Option<T> {
+		if Self::bound() == 0 {
+			return Some(item)
+		}
+		let evicted = if self.0.len() >= Self::bound() { self.0.pop_front() } else { None };
+		self.0.push_back(item);
+		evicted
+	}
+
+	/// Iterate over the items, from the oldest to the most recent.
+	pub fn iter(&self) -> alloc::collections::vec_deque::Iter<'_, T> {
+		self.0.iter()
+	}
+
+	/// Returns the number of items in the buffer.
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns `true` if the buffer contains no items.
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Consume self, and return the inner `VecDeque`, oldest item first.
+	pub fn into_inner(self) -> VecDeque<T> {
+		self.0
+	}
+}
+
+impl<T, S> Default for RingBuffer<T, S>
+where
+	S: Get<u32>,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, S> Clone for RingBuffer<T, S>
+where
+	T: Clone,
+{
+	fn clone(&self) -> Self {
+		RingBuffer(self.0.clone(), PhantomData)
+	}
+}
+
+impl<T, S> core::fmt::Debug for RingBuffer<T, S>
+where
+	T: core::fmt::Debug,
+	S: Get<u32>,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("RingBuffer").field(&self.0).field(&Self::bound()).finish()
+	}
+}
+
+impl<T, S1, S2> PartialEq<RingBuffer<T, S1>> for RingBuffer<T, S2>
+where
+	T: PartialEq,
+	S1: Get<u32>,
+	S2: Get<u32>,
+{
+	fn eq(&self, other: &RingBuffer<T, S1>) -> bool {
+		S1::get() == S2::get() && self.0 == other.0
+	}
+}
+
+impl<T, S> Eq for RingBuffer<T, S>
+where
+	T: Eq,
+	S: Get<u32>,
+{
+}
+
+impl<'a, T, S> IntoIterator for &'a RingBuffer<T, S> {
+	type Item = &'a T;
+	type IntoIter = alloc::collections::vec_deque::Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{ConstU32, ConstUint};
+	use alloc::{vec, vec::Vec};
+
+	#[test]
+	fn push_evicts_oldest_first() {
+		let mut buffer = RingBuffer::<u32, ConstU32<3>>::new();
+		assert_eq!(buffer.push(1), None);
+		assert_eq!(buffer.push(2), None);
+		assert_eq!(buffer.push(3), None);
+		assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+		assert_eq!(buffer.push(4), Some(1));
+		assert_eq!(buffer.push(5), Some(2));
+		assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+	}
+
+	#[test]
+	fn len_never_exceeds_bound() {
+		let mut buffer = RingBuffer::<u32, ConstUint<4>>::default();
+		for i in 0..20 {
+			buffer.push(i);
+			assert!(buffer.len() <= RingBuffer::<u32, ConstUint<4>>::bound());
+		}
+		assert_eq!(buffer.len(), 4);
+		assert_eq!(buffer.into_inner(), vec![16, 17, 18, 19]);
+	}
+
+	#[test]
+	fn zero_bound_keeps_nothing() {
+		let mut buffer = RingBuffer::<u32, ConstU32<0>>::new();
+		assert_eq!(buffer.push(1), Some(1));
+		assert!(buffer.is_empty());
+	}
+}
@@ -230,6 +230,20 @@ impl<T, S: Get<u32>> WeakBoundedVec<T, S> {
 	pub fn is_full(&self) -> bool {
 		self.len() >= Self::bound()
 	}
+
+	/// Returns true if the length of this collection does not exceed the bound.
+	pub fn is_within_bound(&self) -> bool {
+		self.len() <= Self::bound()
+	}
+
+	/// Truncates the collection to the bound, dropping the excess elements at the end.
+	///
+	/// Returns the number of dropped elements, zero if the collection was within the bound.
+	pub fn force_truncate(&mut self) -> usize {
+		let excess = self.len().saturating_sub(Self::bound());
+		self.0.truncate(Self::bound());
+		excess
+	}
 }
 
 impl<T, S> Default for WeakBoundedVec<T, S> {
@@ -534,4 +548,21 @@ mod test {
 		assert!(bounded.try_insert(0, 9).is_err());
 		assert_eq!(*bounded, vec![1, 0, 2, 3]);
 	}
+
+	#[test]
+	fn force_truncate_works() {
+		let mut bounded = WeakBoundedVec::<u32, ConstU32<4>>::force_from(vec![1, 2, 3, 4, 5, 6], None);
+		assert!(!bounded.is_within_bound());
+		assert_eq!(bounded.force_truncate(), 2);
+		assert_eq!(*bounded, vec![1, 2, 3, 4]);
+		assert!(bounded.is_within_bound());
+
+		assert_eq!(bounded.force_truncate(), 0);
+		assert_eq!(bounded.len(), 4);
+
+		let mut bounded: WeakBoundedVec<u32, ConstU32<4>> = vec![1, 2].try_into().unwrap();
+		assert!(bounded.is_within_bound());
+		assert_eq!(bounded.force_truncate(), 0);
+		assert_eq!(*bounded, vec![1, 2]);
+	}
 }
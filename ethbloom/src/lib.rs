@@ -48,6 +48,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "serialize")]
+extern crate alloc;
+
 use core::{mem, ops};
 
 use crunchy::unroll;
@@ -60,6 +63,9 @@ use impl_rlp::impl_fixed_hash_rlp;
 use impl_serde::impl_fixed_hash_serde;
 use tiny_keccak::{Hasher, Keccak};
 
+#[cfg(feature = "serialize")]
+pub mod serde_compact;
+
 // 3 according to yellowpaper
 const BLOOM_BITS: u32 = 3;
 const BLOOM_SIZE: usize = 256;
@@ -172,6 +178,14 @@ impl Bloom {
 	}
 
 	pub fn accrue(&mut self, input: Input<'_>) {
+		self.accrue_many(core::iter::once(input))
+	}
+
+	/// Accrues all `inputs`, with the same result as calling `accrue` for each of them.
+	pub fn accrue_many<'a, I>(&mut self, inputs: I)
+	where
+		I: IntoIterator<Item = Input<'a>>,
+	{
 		let p = BLOOM_BITS;
 
 		let m = self.0.len();
@@ -179,30 +193,36 @@ impl Bloom {
 		let mask = bloom_bits - 1;
 		let bloom_bytes = (log2(bloom_bits) + 7) / 8;
 
-		let hash: Hash<'_> = input.into();
-
 		// must be a power of 2
 		assert_eq!(m & (m - 1), 0);
-		// out of range
-		assert!(p * bloom_bytes <= hash.len() as u32);
+		assert_eq!(BLOOM_BITS, 3);
 
-		let mut ptr = 0;
+		for input in inputs {
+			let hash: Hash<'_> = input.into();
 
-		assert_eq!(BLOOM_BITS, 3);
-		unroll! {
-			for i in 0..3 {
-				let _ = i;
-				let mut index = 0 as usize;
-				for _ in 0..bloom_bytes {
-					index = (index << 8) | hash[ptr] as usize;
-					ptr += 1;
+			// out of range
+			assert!(p * bloom_bytes <= hash.len() as u32);
+
+			let mut ptr = 0;
+
+			unroll! {
+				for i in 0..3 {
+					let _ = i;
+					let mut index = 0 as usize;
+					for _ in 0..bloom_bytes {
+						index = (index << 8) | hash[ptr] as usize;
+						ptr += 1;
+					}
+					index &= mask;
+					self.0[m - 1 - index / 8] |= 1 << (index % 8);
 				}
-				index &= mask;
-				self.0[m - 1 - index / 8] |= 1 << (index % 8);
 			}
 		}
 	}
 
+	/// Merges `bloom` into `self`.
+	///
+	/// Equivalent to `*self |= bloom` for `Bloom`s; intersections are available through `&` and `&=`.
 	pub fn accrue_bloom<'a, B>(&mut self, bloom: B)
 	where
 		BloomRef<'a>: From<B>,
@@ -218,6 +238,18 @@ impl Bloom {
 	pub fn data(&self) -> &[u8; BLOOM_SIZE] {
 		&self.0
 	}
+
+	/// Iterates over the indices (in `0..2048`) of the bits that are set, in ascending order.
+	///
+	/// Indices use the same numbering as `accrue`, i.e. index `0` is the lowest bit of the last byte.
+	pub fn set_bits(&self) -> impl Iterator<Item = usize> + '_ {
+		(0..BLOOM_SIZE * 8).filter(move |index| self.0[BLOOM_SIZE - 1 - index / 8] & (1 << (index % 8)) != 0)
+	}
+
+	/// Returns the number of bits that are set.
+	pub fn count_ones(&self) -> u32 {
+		self.0.iter().map(|byte| byte.count_ones()).sum()
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -314,4 +346,69 @@ mod tests {
 		assert!(my_bloom.contains_input(Input::Raw(&topic)));
 		assert_eq!(my_bloom, bloom);
 	}
+
+	#[test]
+	fn accrue_many_matches_accrue() {
+		let mut inputs = [[0u8; 4]; 64];
+		for (i, input) in inputs.iter_mut().enumerate() {
+			*input = (i as u32 * 7919).to_be_bytes();
+		}
+		let hash = hex!("02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc");
+
+		let mut expected = Bloom::default();
+		for input in &inputs {
+			expected.accrue(Input::Raw(input));
+		}
+		expected.accrue(Input::Hash(&hash));
+
+		let mut bloom = Bloom::default();
+		bloom.accrue_many(inputs.iter().map(|input| Input::Raw(input)).chain(Some(Input::Hash(&hash))));
+		assert_eq!(bloom, expected);
+
+		let mut unchanged = expected;
+		unchanged.accrue_many(None);
+		assert_eq!(unchanged, expected);
+	}
+
+	#[test]
+	#[allow(clippy::op_ref)]
+	fn union_and_intersection() {
+		let address = hex!("ef2d6d194084c2de36e0dabfce45d046b37d1106");
+		let topic = hex!("02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc");
+		let address_bloom = Bloom::from(Input::Raw(&address));
+		let topic_bloom = Bloom::from(Input::Raw(&topic));
+
+		let mut accrued = address_bloom;
+		accrued.accrue_bloom(&topic_bloom);
+		assert_eq!(address_bloom | topic_bloom, accrued);
+		assert_eq!(&address_bloom | &topic_bloom, accrued);
+		let mut union = address_bloom;
+		union |= topic_bloom;
+		assert_eq!(union, accrued);
+
+		assert_eq!(accrued & address_bloom, address_bloom);
+		assert_eq!(&accrued & &topic_bloom, topic_bloom);
+		assert!((address_bloom & topic_bloom).is_empty());
+		let mut intersection = accrued;
+		intersection &= address_bloom;
+		assert_eq!(intersection, address_bloom);
+	}
+
+	#[test]
+	fn set_bits() {
+		let mut hash = [0u8; 32];
+		hash[..6].copy_from_slice(&[0x00, 0x01, 0x08, 0x02, 0x07, 0xff]);
+		let bloom = Bloom::from(Input::Hash(&hash));
+		assert!(bloom.set_bits().eq([1, 2, 2047]));
+		assert_eq!(bloom.count_ones(), 3);
+
+		let empty = Bloom::default();
+		assert_eq!(empty.set_bits().count(), 0);
+		assert_eq!(empty.count_ones(), 0);
+
+		let address = hex!("ef2d6d194084c2de36e0dabfce45d046b37d1106");
+		let bloom = Bloom::from(Input::Raw(&address));
+		assert_eq!(bloom.set_bits().count(), bloom.count_ones() as usize);
+		assert!(bloom.set_bits().all(|index| index < 2048));
+	}
 }
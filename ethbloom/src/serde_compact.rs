@@ -0,0 +1,163 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Serde helpers representing blooms in their compact hex form.
+//!
+//! Meant to be used with `#[serde(with = "ethbloom::serde_compact")]` on fields
+//! that should not use the default 512 hex characters representation.
+//! See [`Bloom::to_compact_hex`] for the format.
+
+use crate::{Bloom, BLOOM_SIZE};
+use alloc::string::String;
+use core::fmt;
+use impl_serde::{
+	serde::{de, Deserializer, Serializer},
+	serialize::{from_hex, to_hex, FromHexError},
+};
+
+/// Error returned by [`Bloom::from_compact_hex`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum FromCompactHexError {
+	/// Invalid (non-hex) character encountered.
+	InvalidHex {
+		/// The unexpected character.
+		character: char,
+		/// Index of that occurrence.
+		index: usize,
+	},
+	/// The input encodes more bytes than fit in a bloom.
+	TooLong(usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromCompactHexError {}
+
+impl fmt::Display for FromCompactHexError {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidHex { character, index } => write!(fmt, "invalid hex character: {}, at {}", character, index),
+			Self::TooLong(len) => write!(fmt, "expected at most {} bytes, got {}", BLOOM_SIZE, len),
+		}
+	}
+}
+
+impl Bloom {
+	/// Returns the compact hex form of the bloom: a `0x`-prefixed hex string
+	/// with the trailing zero bytes trimmed, e.g. `0x` for an empty bloom.
+	pub fn to_compact_hex(&self) -> String {
+		let len = self.0.iter().rposition(|byte| *byte != 0).map_or(0, |last| last + 1);
+		to_hex(&self.0[..len], false)
+	}
+
+	/// Parses the compact hex form produced by [`Bloom::to_compact_hex`], with or without `0x` prefix.
+	///
+	/// The decoded bytes are the leading bytes of the bloom, the remaining ones are zero.
+	pub fn from_compact_hex(input: &str) -> Result<Self, FromCompactHexError> {
+		let bytes = from_hex(input).map_err(|err| match err {
+			FromHexError::InvalidHex { character, index } => FromCompactHexError::InvalidHex { character, index },
+			#[allow(deprecated)]
+			FromHexError::MissingPrefix => unreachable!("the prefix is optional; qed"),
+		})?;
+		if bytes.len() > BLOOM_SIZE {
+			return Err(FromCompactHexError::TooLong(bytes.len()))
+		}
+		let mut bloom = Bloom::zero();
+		bloom.0[..bytes.len()].copy_from_slice(&bytes);
+		Ok(bloom)
+	}
+}
+
+/// Serialize a bloom in its compact hex form.
+pub fn serialize<S>(bloom: &Bloom, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&bloom.to_compact_hex())
+}
+
+/// Deserialize a bloom from its compact hex form.
+///
+/// The full form is accepted as well, since it is a compact form without trimmed bytes.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Bloom, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	struct Visitor;
+
+	impl<'de> de::Visitor<'de> for Visitor {
+		type Value = Bloom;
+
+		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+			write!(formatter, "a hex string of at most {} bytes", BLOOM_SIZE)
+		}
+
+		fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+			Bloom::from_compact_hex(v).map_err(E::custom)
+		}
+	}
+
+	deserializer.deserialize_str(Visitor)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Input;
+	use alloc::format;
+
+	fn sparse_bloom() -> Bloom {
+		let mut bloom = Bloom::zero();
+		bloom.accrue(Input::Raw(b"topic"));
+		bloom.0[3] = 0;
+		bloom.0[200] = 0x80;
+		bloom
+	}
+
+	#[test]
+	fn compact_hex_roundtrip() {
+		let full = {
+			let mut full = Bloom::zero();
+			full.0[BLOOM_SIZE - 1] = 1;
+			full
+		};
+		for bloom in [Bloom::zero(), sparse_bloom(), full, Bloom::repeat_byte(0xff)] {
+			let compact = bloom.to_compact_hex();
+			assert_eq!(Bloom::from_compact_hex(&compact), Ok(bloom));
+		}
+
+		assert_eq!(Bloom::zero().to_compact_hex(), "0x");
+		let mut bloom = Bloom::zero();
+		bloom.0[1] = 0x2a;
+		assert_eq!(bloom.to_compact_hex(), "0x002a");
+		assert_eq!(Bloom::from_compact_hex("002a"), Ok(bloom));
+		assert_eq!(Bloom::from_compact_hex(&format!("{:#x}", bloom)), Ok(bloom));
+	}
+
+	#[test]
+	fn compact_hex_rejects_invalid_input() {
+		assert_eq!(Bloom::from_compact_hex("0x0g"), Err(FromCompactHexError::InvalidHex { character: 'g', index: 3 }));
+		let too_long = format!("0x{}", "00".repeat(BLOOM_SIZE + 1));
+		assert_eq!(Bloom::from_compact_hex(&too_long), Err(FromCompactHexError::TooLong(BLOOM_SIZE + 1)));
+	}
+
+	#[test]
+	fn serde_with_compact_form() {
+		#[derive(serde_derive::Serialize, serde_derive::Deserialize, PartialEq, Debug)]
+		#[serde(crate = "impl_serde::serde")]
+		struct Log {
+			#[serde(with = "super")]
+			bloom: Bloom,
+		}
+
+		let log = Log { bloom: sparse_bloom() };
+		let json = serde_json::to_string(&log).unwrap();
+		assert_eq!(json, format!("{{\"bloom\":\"{}\"}}", log.bloom.to_compact_hex()));
+		assert!(json.len() < 2 * BLOOM_SIZE);
+		assert_eq!(serde_json::from_str::<Log>(&json).unwrap(), log);
+	}
+}
@@ -31,7 +31,7 @@ pub use primitive_types::{U128, U256, U512};
 
 #[cfg(test)]
 mod tests {
-	use super::{U256, U512};
+	use super::{U128, U256, U512};
 	use serde_json as ser;
 	use std::u64::MAX;
 
@@ -108,6 +108,21 @@ mod tests {
 		assert_eq!(raw, new_raw);
 	}
 
+	#[test]
+	fn saturating_narrowing_conversions() {
+		let u256_max = U512::from(U256::MAX);
+		assert_eq!(u256_max.saturating_into_u256(), U256::MAX);
+		assert_eq!((u256_max + 1).saturating_into_u256(), U256::MAX);
+		assert_eq!(U512::MAX.saturating_into_u256(), U256::MAX);
+		assert_eq!(U512::from(12345).saturating_into_u256(), U256::from(12345));
+
+		let u128_max = U256::from(U128::MAX);
+		assert_eq!(u128_max.saturating_into_u128(), U128::MAX);
+		assert_eq!((u128_max + 1).saturating_into_u128(), U128::MAX);
+		assert_eq!(U256::MAX.saturating_into_u128(), U128::MAX);
+		assert_eq!(U256([7, 1, 0, 0]).saturating_into_u128(), U128([7, 1]));
+	}
+
 	#[test]
 	fn u256_multi_full_mul() {
 		let result = U256([0, 0, 0, 0]).full_mul(U256([0, 0, 0, 0]));
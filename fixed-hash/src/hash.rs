@@ -196,6 +196,20 @@ macro_rules! construct_fixed_hash {
 				ret
 			}
 
+			/// Create a new fixed-hash from the given slice `src`, failing if its length does not match.
+			///
+			/// # Note
+			///
+			/// The given bytes are interpreted in big endian order.
+			pub fn try_from_slice(src: &[u8]) -> $crate::core_::result::Result<Self, $crate::FixedHashError> {
+				if src.len() != $n_bytes {
+					return Err($crate::FixedHashError::InvalidLength { expected: $n_bytes, actual: src.len() })
+				}
+				let mut ret = Self::zero();
+				ret.as_bytes_mut().copy_from_slice(src);
+				Ok(ret)
+			}
+
 			/// Returns `true` if all bits set in `b` are also set in `self`.
 			#[inline]
 			pub fn covers(&self, b: &Self) -> bool {
@@ -207,6 +221,45 @@ macro_rules! construct_fixed_hash {
 			pub fn is_zero(&self) -> bool {
 				self.as_bytes().iter().all(|&byte| byte == 0u8)
 			}
+
+			/// Returns the XOR distance between `self` and `other`, as used by Kademlia.
+			#[inline]
+			pub fn distance(&self, other: &Self) -> Self {
+				*self ^ *other
+			}
+
+			/// Returns the number of leading zero bits of the big-endian byte array.
+			#[inline]
+			pub fn leading_zeros(&self) -> u32 {
+				match self.0.iter().position(|&byte| byte != 0) {
+					Some(index) => index as u32 * 8 + self.0[index].leading_zeros(),
+					None => ($n_bytes * 8) as u32,
+				}
+			}
+
+			/// Rotates the bits of the whole big-endian byte array `bits` places to the left.
+			///
+			/// Bit counts larger than the hash width are reduced modulo the bit length.
+			#[inline]
+			pub fn rotate_left(&self, bits: u32) -> Self {
+				let bits = bits % ($n_bytes * 8) as u32;
+				if bits == 0 {
+					return *self
+				}
+				(*self << bits) | (*self >> (($n_bytes * 8) as u32 - bits))
+			}
+
+			/// Rotates the bits of the whole big-endian byte array `bits` places to the right.
+			///
+			/// Bit counts larger than the hash width are reduced modulo the bit length.
+			#[inline]
+			pub fn rotate_right(&self, bits: u32) -> Self {
+				let bits = bits % ($n_bytes * 8) as u32;
+				if bits == 0 {
+					return *self
+				}
+				(*self >> bits) | (*self << (($n_bytes * 8) as u32 - bits))
+			}
 		}
 
 		impl $crate::core_::fmt::Debug for $name {
@@ -313,6 +366,7 @@ macro_rules! construct_fixed_hash {
 		impl_bit_ops_for_fixed_hash!($name, BitOr, bitor, BitOrAssign, bitor_assign, |, |=);
 		impl_bit_ops_for_fixed_hash!($name, BitAnd, bitand, BitAndAssign, bitand_assign, &, &=);
 		impl_bit_ops_for_fixed_hash!($name, BitXor, bitxor, BitXorAssign, bitxor_assign, ^, ^=);
+		impl_shift_ops_for_fixed_hash!($name);
 
 		impl_byteorder_for_fixed_hash!($name);
 
@@ -320,6 +374,7 @@ macro_rules! construct_fixed_hash {
 		impl_rustc_hex_for_fixed_hash!($name);
 		impl_quickcheck_for_fixed_hash!($name);
 		impl_arbitrary_for_fixed_hash!($name);
+		impl_const_time_for_fixed_hash!($name);
 	}
 }
 
@@ -371,6 +426,72 @@ macro_rules! impl_bit_ops_for_fixed_hash {
 	};
 }
 
+/// Implements `Shl<u32>` and `Shr<u32>` over the big-endian byte array of the hash.
+///
+/// Shift amounts larger than the hash width are reduced modulo the bit length,
+/// like the `wrapping_shl`/`wrapping_shr` methods of the primitive integers.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_shift_ops_for_fixed_hash {
+	( $name:ident ) => {
+		impl $crate::core_::ops::ShlAssign<u32> for $name {
+			fn shl_assign(&mut self, bits: u32) {
+				let len = Self::len_bytes();
+				let bits = bits as usize % (len * 8);
+				let (byte_shift, bit_shift) = (bits / 8, bits % 8);
+				let src = self.0;
+				for i in 0..len {
+					let from = i + byte_shift;
+					self.0[i] = if from < len { src[from] << bit_shift } else { 0 };
+					if bit_shift > 0 && from + 1 < len {
+						self.0[i] |= src[from + 1] >> (8 - bit_shift);
+					}
+				}
+			}
+		}
+
+		impl $crate::core_::ops::ShrAssign<u32> for $name {
+			fn shr_assign(&mut self, bits: u32) {
+				let len = Self::len_bytes();
+				let bits = bits as usize % (len * 8);
+				let (byte_shift, bit_shift) = (bits / 8, bits % 8);
+				let src = self.0;
+				for i in 0..len {
+					if i < byte_shift {
+						self.0[i] = 0;
+						continue
+					}
+					let from = i - byte_shift;
+					self.0[i] = src[from] >> bit_shift;
+					if bit_shift > 0 && from > 0 {
+						self.0[i] |= src[from - 1] << (8 - bit_shift);
+					}
+				}
+			}
+		}
+
+		impl $crate::core_::ops::Shl<u32> for $name {
+			type Output = $name;
+
+			#[inline]
+			fn shl(mut self, bits: u32) -> Self::Output {
+				self <<= bits;
+				self
+			}
+		}
+
+		impl $crate::core_::ops::Shr<u32> for $name {
+			type Output = $name;
+
+			#[inline]
+			fn shr(mut self, bits: u32) -> Self::Output {
+				self >>= bits;
+				self
+			}
+		}
+	};
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! impl_byteorder_for_fixed_hash {
@@ -470,6 +591,38 @@ macro_rules! impl_byteorder_for_fixed_hash {
 			pub fn from_low_u64_ne(val: u64) -> Self {
 				Self::from_low_u64_with_fn(val, u64::to_ne_bytes)
 			}
+
+			fn from_low_u128_with_fn(val: u128, to_bytes: fn(u128) -> [u8; 16]) -> Self {
+				let buf = to_bytes(val);
+				let capped = $crate::core_::cmp::min(Self::len_bytes(), 16);
+				let mut bytes = [0x0; $crate::core_::mem::size_of::<Self>()];
+				bytes[(Self::len_bytes() - capped)..].copy_from_slice(&buf[..capped]);
+				Self::from_slice(&bytes)
+			}
+
+			/// Creates a new hash type from the given `u128` value.
+			///
+			/// # Note
+			///
+			/// - The given `u128` value is interpreted as big endian.
+			/// - Ignores the most significant bits of the given value
+			///   if the hash type has less than 16 bytes.
+			#[inline]
+			pub fn from_low_u128_be(val: u128) -> Self {
+				Self::from_low_u128_with_fn(val, u128::to_be_bytes)
+			}
+
+			/// Creates a new hash type from the given `u128` value.
+			///
+			/// # Note
+			///
+			/// - The given `u128` value is interpreted as little endian.
+			/// - Ignores the most significant bits of the given value
+			///   if the hash type has less than 16 bytes.
+			#[inline]
+			pub fn from_low_u128_le(val: u128) -> Self {
+				Self::from_low_u128_with_fn(val, u128::to_le_bytes)
+			}
 		}
 	};
 }
@@ -675,6 +828,51 @@ macro_rules! impl_arbitrary_for_fixed_hash {
 	};
 }
 
+// When the `const-time` feature is disabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `subtle` crate feature in
+// a user crate.
+#[cfg(not(feature = "const-time"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_const_time_for_fixed_hash {
+	( $name:ident ) => {};
+}
+
+// When the `const-time` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `subtle` crate feature in
+// a user crate.
+#[cfg(feature = "const-time")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_const_time_for_fixed_hash {
+	( $name:ident ) => {
+		impl $crate::subtle::ConstantTimeEq for $name {
+			fn ct_eq(&self, other: &Self) -> $crate::subtle::Choice {
+				$crate::subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..])
+			}
+		}
+
+		impl $name {
+			/// Compares `self` and `other` in constant time.
+			///
+			/// Unlike `==`, this does not return early on the first differing byte,
+			/// so it should be preferred when comparing secret-derived values such as MACs.
+			#[inline]
+			pub fn constant_time_eq(&self, other: &Self) -> bool {
+				$crate::subtle::ConstantTimeEq::ct_eq(self, other).into()
+			}
+		}
+	};
+}
+
 /// Implements lossy conversions between the given types.
 ///
 /// # Note
@@ -46,9 +46,37 @@ pub use quickcheck;
 #[doc(hidden)]
 pub use arbitrary;
 
+#[cfg(feature = "const-time")]
+#[doc(hidden)]
+pub use subtle;
+
 #[macro_use]
 mod hash;
 
+/// Error returned by the fallible fixed-hash constructors.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FixedHashError {
+	/// The input length does not match the number of bytes of the hash type.
+	InvalidLength {
+		/// The number of bytes of the hash type.
+		expected: usize,
+		/// The length of the given input.
+		actual: usize,
+	},
+}
+
+impl core::fmt::Display for FixedHashError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match *self {
+			FixedHashError::InvalidLength { expected, actual } =>
+				write!(f, "invalid length: expected {} bytes, got {}", expected, actual),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixedHashError {}
+
 #[cfg(test)]
 mod tests;
 
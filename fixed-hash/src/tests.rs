@@ -28,6 +28,17 @@ mod repeat_byte {
 		assert_eq!(H32::repeat_byte(0x0), H32::zero());
 		assert_eq!(H32::repeat_byte(0x0), H32::from([0x0; 4]));
 	}
+
+	#[test]
+	fn const_context() {
+		const ZERO: H256 = H256::zero();
+		static TABLE: [H32; 3] = [H32::zero(), H32::repeat_byte(0x01), H32::repeat_byte(0xFF)];
+
+		assert!(ZERO.is_zero());
+		assert_eq!(TABLE[0], H32::from([0x00; 4]));
+		assert_eq!(TABLE[1], H32::from([0x01; 4]));
+		assert_eq!(TABLE[2], H32::from([0xFF; 4]));
+	}
 }
 
 #[test]
@@ -39,6 +50,27 @@ fn len_bytes() {
 	assert_eq!(H256::len_bytes(), 32);
 }
 
+#[cfg(feature = "const-time")]
+#[test]
+fn constant_time_eq() {
+	use subtle::ConstantTimeEq;
+
+	let a = H256::from_low_u64_be(0x1234);
+	assert!(a.constant_time_eq(&a));
+	assert!(H256::zero().constant_time_eq(&H256::zero()));
+	assert!(!a.constant_time_eq(&H256::zero()));
+	assert!(!H256::repeat_byte(0xff).constant_time_eq(&H256::repeat_byte(0xfe)));
+
+	let mut b = a;
+	b.0[0] ^= 0x80;
+	assert!(!a.constant_time_eq(&b));
+	assert!(H160::repeat_byte(0xaa).constant_time_eq(&H160::repeat_byte(0xaa)));
+
+	assert!(bool::from(a.ct_eq(&a)));
+	assert!(!bool::from(a.ct_eq(&b)));
+	assert_eq!(a.ct_eq(&b).unwrap_u8(), 0);
+}
+
 #[test]
 fn as_bytes() {
 	assert_eq!(H32::from([0x55; 4]).as_bytes(), &[0x55; 4]);
@@ -93,6 +125,26 @@ mod from_slice {
 	}
 }
 
+mod try_from_slice {
+	use super::*;
+	use crate::FixedHashError;
+
+	#[test]
+	fn simple() {
+		assert_eq!(H32::try_from_slice(&[0x10; 4]), Ok(H32::from([0x10; 4])));
+	}
+
+	#[test]
+	fn too_few_elems() {
+		assert_eq!(H32::try_from_slice(&[0x10; 3]), Err(FixedHashError::InvalidLength { expected: 4, actual: 3 }));
+	}
+
+	#[test]
+	fn too_many_elems() {
+		assert_eq!(H32::try_from_slice(&[0x10; 5]), Err(FixedHashError::InvalidLength { expected: 4, actual: 5 }));
+	}
+}
+
 mod covers {
 	use super::*;
 
@@ -155,6 +207,34 @@ mod is_zero {
 	}
 }
 
+mod distance {
+	use super::*;
+
+	#[test]
+	fn symmetric() {
+		let a = H256::from_low_u64_be(0x1234_5678);
+		let b = H256::repeat_byte(0xA5);
+		assert_eq!(a.distance(&b), b.distance(&a));
+		assert_eq!(a.distance(&b), a ^ b);
+		assert_eq!(a.distance(&H256::zero()), a);
+	}
+
+	#[test]
+	fn to_self_is_zero() {
+		let a = H256::repeat_byte(42);
+		assert!(a.distance(&a).is_zero());
+		assert_eq!(a.distance(&a).leading_zeros(), 256);
+	}
+
+	#[test]
+	fn leading_zeros() {
+		assert_eq!(H32::zero().leading_zeros(), 32);
+		assert_eq!(H32::repeat_byte(0xFF).leading_zeros(), 0);
+		assert_eq!(H32::from([0, 0b0001_0000, 0xFF, 0]).leading_zeros(), 11);
+		assert_eq!(H256::from_low_u64_be(1).leading_zeros(), 255);
+	}
+}
+
 mod to_low_u64 {
 	use super::*;
 
@@ -235,6 +315,46 @@ mod from_low_u64 {
 	}
 }
 
+mod from_low_u128 {
+	use super::*;
+
+	#[test]
+	fn smaller_size() {
+		let val = 0x0123_4567_89AB_CDEF_0011_2233_4455_6677;
+		assert_eq!(H64::from_low_u128_be(val), H64::from([0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]));
+		assert_eq!(H64::from_low_u128_le(val), H64::from([0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00]));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn equal_size() {
+		let val = 0x0123_4567_89AB_CDEF_0011_2233_4455_6677;
+		assert_eq!(
+			H128::from_low_u128_be(val),
+			H128::from([
+				0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+				0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77
+			])
+		);
+		assert_eq!(
+			H128::from_low_u128_le(val),
+			H128::from([
+				0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00,
+				0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01
+			])
+		)
+	}
+
+	#[test]
+	fn larger_size() {
+		let val = 0x0123_4567_89AB_CDEF_0011_2233_4455_6677;
+		assert_eq!(H256::from_low_u128_be(val).as_bytes()[16..], val.to_be_bytes());
+		assert_eq!(H256::from_low_u128_le(val).as_bytes()[16..], val.to_le_bytes());
+		assert!(H256::from_low_u128_be(val).as_bytes()[..16].iter().all(|&byte| byte == 0));
+		assert_eq!(H256::from_low_u128_be(0xABCD), H256::from_low_u64_be(0xABCD));
+	}
+}
+
 #[cfg(feature = "rand")]
 mod rand {
 	use super::*;
@@ -405,4 +525,62 @@ mod ops {
 			])
 		)
 	}
+
+	fn bit(hash: &H256, index: usize) -> bool {
+		// Bit 0 is the most significant bit of the first byte.
+		hash[index / 8] & (0x80 >> (index % 8)) != 0
+	}
+
+	fn from_bits(f: impl Fn(usize) -> bool) -> H256 {
+		let mut ret = H256::zero();
+		for index in 0..256 {
+			if f(index) {
+				ret.0[index / 8] |= 0x80 >> (index % 8);
+			}
+		}
+		ret
+	}
+
+	fn sample() -> H256 {
+		let mut ret = H256::zero();
+		for (i, byte) in ret.as_bytes_mut().iter_mut().enumerate() {
+			*byte = (i as u8).wrapping_mul(37) ^ 0xa5;
+		}
+		ret
+	}
+
+	const SHIFTS: [u32; 9] = [0, 1, 7, 8, 9, 31, 100, 255, 256 + 13];
+
+	#[test]
+	fn shl_and_shr() {
+		let hash = sample();
+		for &shift in SHIFTS.iter() {
+			let n = shift as usize % 256;
+			assert_eq!(hash << shift, from_bits(|i| i + n < 256 && bit(&hash, i + n)), "shl {}", shift);
+			assert_eq!(hash >> shift, from_bits(|i| i >= n && bit(&hash, i - n)), "shr {}", shift);
+		}
+
+		let mut assigned = hash;
+		assigned <<= 12;
+		assert_eq!(assigned, hash << 12);
+		assigned >>= 12;
+		assert_eq!(assigned, hash << 12 >> 12);
+	}
+
+	#[test]
+	fn rotate_left_and_right() {
+		let hash = sample();
+		for &shift in SHIFTS.iter() {
+			let n = shift as usize % 256;
+			assert_eq!(hash.rotate_left(shift), from_bits(|i| bit(&hash, (i + n) % 256)), "rotate_left {}", shift);
+			assert_eq!(
+				hash.rotate_right(shift),
+				from_bits(|i| bit(&hash, (i + 256 - n) % 256)),
+				"rotate_right {}",
+				shift
+			);
+			assert_eq!(hash.rotate_left(shift).rotate_right(shift), hash);
+		}
+		assert_eq!(H32::from([0x80, 0, 0, 0x01]).rotate_left(1), H32::from([0, 0, 0, 0x03]));
+	}
 }
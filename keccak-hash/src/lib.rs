@@ -11,7 +11,7 @@
 #[cfg(feature = "std")]
 use std::io;
 
-pub use primitive_types::H256;
+pub use primitive_types::{H256, H512};
 use tiny_keccak::{Hasher, Keccak};
 
 /// Get the KECCAK (i.e. Keccak) hash of the empty bytes string.
@@ -38,6 +38,37 @@ pub fn keccak<T: AsRef<[u8]>>(s: T) -> H256 {
 	H256(result)
 }
 
+/// Computes the keccak256 hash of the concatenation of `parts`, without allocating a combined buffer.
+pub fn keccak256_concat(parts: &[&[u8]]) -> H256 {
+	let mut hasher = Keccak256Hasher::new();
+	for part in parts {
+		hasher.update(part);
+	}
+	hasher.finalize()
+}
+
+/// Computes the keccak256 hash of `a` followed by `b`, same as `keccak([a, b].concat())`.
+pub fn keccak_concat<A: AsRef<[u8]>, B: AsRef<[u8]>>(a: A, b: B) -> H256 {
+	keccak256_concat(&[a.as_ref(), b.as_ref()])
+}
+
+/// Computes the keccak256 hash of `data[range]`, without copying the subslice.
+///
+/// Unlike [`keccak256_range`], `data` is left untouched and the hash is returned.
+///
+/// # Panics
+///
+/// If `range` is out of bounds.
+pub fn keccak_range(data: &[u8], range: core::ops::Range<usize>) -> H256 {
+	assert!(
+		range.start <= range.end && range.end <= data.len(),
+		"range {:?} out of bounds for data of length {}",
+		range,
+		data.len(),
+	);
+	keccak(&data[range])
+}
+
 /// Computes in-place keccak256 hash of `data`.
 pub fn keccak256(data: &mut [u8]) {
 	let mut keccak256 = Keccak::v256();
@@ -113,12 +144,79 @@ pub fn keccak_512(input: &[u8], output: &mut [u8]) {
 	keccak512.finalize(output);
 }
 
+/// Computes the keccak512 hash of `input`, returning it in a new array.
+///
+/// Unlike [`keccak512`], the input is left untouched and can have any length.
+pub fn keccak_512_hash(input: &[u8]) -> [u8; 64] {
+	let mut output = [0u8; 64];
+	keccak_512(input, &mut output);
+	output
+}
+
 pub fn write_keccak<T: AsRef<[u8]>>(s: T, dest: &mut [u8]) {
 	let mut keccak256 = Keccak::v256();
 	keccak256.update(s.as_ref());
 	keccak256.finalize(dest);
 }
 
+/// Incremental keccak256 hasher, for inputs that are not available as a single slice.
+#[derive(Clone)]
+pub struct Keccak256Hasher(Keccak);
+
+impl Keccak256Hasher {
+	/// Create a new hasher with no data fed into it.
+	pub fn new() -> Self {
+		Keccak256Hasher(Keccak::v256())
+	}
+
+	/// Feed `data` into the hasher.
+	pub fn update(&mut self, data: &[u8]) {
+		self.0.update(data);
+	}
+
+	/// Consume the hasher and return the hash of all data fed into it.
+	pub fn finalize(self) -> H256 {
+		let mut output = [0u8; 32];
+		self.0.finalize(&mut output);
+		H256(output)
+	}
+}
+
+impl Default for Keccak256Hasher {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Incremental keccak512 hasher, for inputs that are not available as a single slice.
+#[derive(Clone)]
+pub struct Keccak512Hasher(Keccak);
+
+impl Keccak512Hasher {
+	/// Create a new hasher with no data fed into it.
+	pub fn new() -> Self {
+		Keccak512Hasher(Keccak::v512())
+	}
+
+	/// Feed `data` into the hasher.
+	pub fn update(&mut self, data: &[u8]) {
+		self.0.update(data);
+	}
+
+	/// Consume the hasher and return the hash of all data fed into it.
+	pub fn finalize(self) -> H512 {
+		let mut output = [0u8; 64];
+		self.0.finalize(&mut output);
+		H512(output)
+	}
+}
+
+impl Default for Keccak512Hasher {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 #[cfg(feature = "std")]
 pub fn keccak_pipe(r: &mut dyn io::BufRead, w: &mut dyn io::Write) -> Result<H256, io::Error> {
 	let mut output = [0u8; 32];
@@ -182,6 +280,67 @@ mod tests {
 		assert_eq!(dest, expected.as_ref());
 	}
 
+	#[test]
+	fn streaming_hashers() {
+		let data = [0x41u8; 300];
+
+		let mut hasher = Keccak256Hasher::new();
+		assert_eq!(hasher.clone().finalize(), KECCAK_EMPTY);
+		for chunk in data.chunks(7) {
+			hasher.update(chunk);
+		}
+		assert_eq!(hasher.finalize(), keccak(&data[..]));
+
+		let mut hasher = Keccak512Hasher::default();
+		hasher.update(&data[..1]);
+		hasher.update(&[]);
+		hasher.update(&data[1..150]);
+		hasher.update(&data[150..]);
+		let mut expected = [0u8; 64];
+		keccak_512(&data, &mut expected);
+		assert_eq!(hasher.finalize(), H512(expected));
+	}
+
+	#[test]
+	fn keccak_512_hash_matches_in_place() {
+		let mut data = [0x2au8; 64];
+		let hash = keccak_512_hash(&data);
+		keccak512(&mut data);
+		assert_eq!(hash, data);
+
+		let data: Vec<u8> = (0..200).collect();
+		let mut expected = [0u8; 64];
+		keccak_512(&data, &mut expected);
+		assert_eq!(keccak_512_hash(&data), expected);
+		assert_eq!(keccak_512_hash(&[]), Keccak512Hasher::new().finalize().0);
+	}
+
+	#[test]
+	fn keccak_concat_matches_concatenation() {
+		let parts: [&[u8]; 4] = [b"hello", b" ", b"", b"world"];
+		for count in 0..=parts.len() {
+			let parts = &parts[..count];
+			assert_eq!(keccak256_concat(parts), keccak(parts.concat()));
+		}
+		assert_eq!(keccak256_concat(&[]), KECCAK_EMPTY);
+		assert_eq!(keccak_concat(b"hello ", "world"), keccak(b"hello world"));
+		assert_eq!(keccak_concat([], []), KECCAK_EMPTY);
+	}
+
+	#[test]
+	fn keccak_range_matches_subslice() {
+		let data: Vec<u8> = (0..=255).collect();
+		for range in [0..0, 0..8, 3..200, 100..256, 0..256] {
+			assert_eq!(keccak_range(&data, range.clone()), keccak(&data[range]));
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "out of bounds")]
+	fn keccak_range_out_of_bounds() {
+		keccak_range(&[0u8; 8], 4..9);
+	}
+
 	#[cfg(feature = "std")]
 	#[test]
 	fn should_keccak_a_file() {
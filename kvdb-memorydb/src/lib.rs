@@ -32,6 +32,15 @@ pub fn create(num_cols: u32) -> InMemory {
 	InMemory { columns: RwLock::new(cols) }
 }
 
+impl InMemory {
+	/// Returns an independent copy of the current state of the database.
+	///
+	/// Unlike sharing the database, writes to either copy are not visible in the other one.
+	pub fn snapshot(&self) -> InMemory {
+		InMemory { columns: RwLock::new(self.columns.read().clone()) }
+	}
+}
+
 fn invalid_column(col: u32) -> io::Error {
 	io::Error::new(io::ErrorKind::Other, format!("No such column family: {:?}", col))
 }
@@ -53,6 +62,18 @@ impl KeyValueDB for InMemory {
 		}
 	}
 
+	fn put_if_absent(&self, col: u32, key: &[u8], value: &[u8]) -> io::Result<bool> {
+		let mut columns = self.columns.write();
+		match columns.get_mut(&col) {
+			None => Err(invalid_column(col)),
+			Some(map) if map.contains_key(key) => Ok(false),
+			Some(map) => {
+				map.insert(key.to_vec(), value.to_vec());
+				Ok(true)
+			},
+		}
+	}
+
 	fn write(&self, transaction: DBTransaction) -> io::Result<()> {
 		let mut columns = self.columns.write();
 		let ops = transaction.ops;
@@ -120,6 +141,7 @@ impl KeyValueDB for InMemory {
 #[cfg(test)]
 mod tests {
 	use super::create;
+	use kvdb::KeyValueDB;
 	use kvdb_shared_tests as st;
 	use std::io;
 
@@ -135,6 +157,18 @@ mod tests {
 		st::test_put_and_get(&db)
 	}
 
+	#[test]
+	fn has_keys() -> io::Result<()> {
+		let db = create(1);
+		st::test_has_keys(&db)
+	}
+
+	#[test]
+	fn put_if_absent() -> io::Result<()> {
+		let db = create(1);
+		st::test_put_if_absent(&db)
+	}
+
 	#[test]
 	fn delete_and_get() -> io::Result<()> {
 		let db = create(1);
@@ -164,4 +198,28 @@ mod tests {
 		let db = create(1);
 		st::test_complex(&db)
 	}
+
+	#[test]
+	fn snapshot_is_independent() -> io::Result<()> {
+		let db = create(2);
+		let mut transaction = db.transaction();
+		transaction.put(0, b"key", b"old");
+		db.write(transaction)?;
+
+		let snapshot = db.snapshot();
+		let mut transaction = db.transaction();
+		transaction.put(0, b"key", b"new");
+		transaction.put(1, b"other", b"value");
+		db.write(transaction)?;
+
+		assert_eq!(snapshot.get(0, b"key")?.unwrap(), b"old");
+		assert_eq!(snapshot.get(1, b"other")?, None);
+		assert_eq!(db.get(0, b"key")?.unwrap(), b"new");
+
+		let mut transaction = snapshot.transaction();
+		transaction.delete(0, b"key");
+		snapshot.write(transaction)?;
+		assert_eq!(db.get(0, b"key")?.unwrap(), b"new");
+		Ok(())
+	}
 }
@@ -15,7 +15,7 @@
 //! To work around this we set an upper bound to the prefix successor.
 //! See https://github.com/facebook/rocksdb/wiki/Prefix-Seek-API-Changes for details.
 
-use crate::{other_io_err, DBAndColumns, DBKeyValue};
+use crate::{rocksdb_io_err, DBAndColumns, DBKeyValue};
 use rocksdb::{DBIterator, Direction, IteratorMode, ReadOptions};
 use std::io;
 
@@ -89,6 +89,6 @@ where
 	fn next(&mut self) -> Option<Self::Item> {
 		self.0
 			.next()
-			.map(|r| r.map_err(other_io_err).map(|(k, v)| (k.into_vec().into(), v.into())))
+			.map(|r| r.map_err(rocksdb_io_err).map(|(k, v)| (k.into_vec().into(), v.into())))
 	}
 }
@@ -14,13 +14,16 @@ use std::{
 	collections::HashMap,
 	error, io,
 	path::{Path, PathBuf},
+	sync::Arc,
 };
 
 use rocksdb::{
-	BlockBasedOptions, ColumnFamily, ColumnFamilyDescriptor, Options, ReadOptions, WriteBatch, WriteOptions, DB,
+	BlockBasedOptions, ColumnFamily, ColumnFamilyDescriptor, DBCompressionType, Options, ReadOptions, WriteBatch,
+	WriteOptions, DB,
 };
 
 use kvdb::{DBKeyValue, DBOp, DBTransaction, DBValue, KeyValueDB};
+use parking_lot::RwLock;
 
 #[cfg(target_os = "linux")]
 use regex::Regex;
@@ -36,6 +39,76 @@ where
 	io::Error::new(io::ErrorKind::Other, e)
 }
 
+fn rocksdb_io_err(e: rocksdb::Error) -> io::Error {
+	other_io_err(DbError::from(e))
+}
+
+/// Classification of a [`DbError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbErrorKind {
+	/// The data on disk is corrupted.
+	Corruption,
+	/// The resource is temporarily busy, the operation may succeed if retried.
+	Busy,
+	/// The database has column families on disk that were not opened,
+	/// e.g. because `DatabaseConfig::columns` is too low.
+	IncompleteColumnFamilies,
+	/// Any other error.
+	Other,
+}
+
+/// An error reported by RocksDB.
+///
+/// The `io::Error`s returned by this crate wrap it whenever RocksDB is the origin of the error,
+/// use [`DbError::from_io_error`] to recover it.
+#[derive(Debug)]
+pub struct DbError {
+	kind: DbErrorKind,
+	inner: rocksdb::Error,
+}
+
+impl DbError {
+	/// Returns the classification of this error.
+	pub fn kind(&self) -> DbErrorKind {
+		self.kind
+	}
+
+	/// Returns the error message reported by RocksDB.
+	pub fn message(&self) -> &str {
+		self.inner.as_ref()
+	}
+
+	/// Returns the `DbError` wrapped by `err`, if any.
+	pub fn from_io_error(err: &io::Error) -> Option<&DbError> {
+		err.get_ref().and_then(|inner| inner.downcast_ref())
+	}
+}
+
+impl From<rocksdb::Error> for DbError {
+	fn from(inner: rocksdb::Error) -> Self {
+		let kind = match inner.kind() {
+			rocksdb::ErrorKind::Corruption => DbErrorKind::Corruption,
+			rocksdb::ErrorKind::Busy | rocksdb::ErrorKind::TryAgain => DbErrorKind::Busy,
+			rocksdb::ErrorKind::InvalidArgument if inner.as_ref().contains("Column families not opened") =>
+				DbErrorKind::IncompleteColumnFamilies,
+			_ => DbErrorKind::Other,
+		};
+		DbError { kind, inner }
+	}
+}
+
+impl std::fmt::Display for DbError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.inner.fmt(f)
+	}
+}
+
+impl error::Error for DbError {
+	fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+		Some(&self.inner)
+	}
+}
+
 fn invalid_column(col: u32) -> io::Error {
 	other_io_err(format!("No such column family: {:?}", col))
 }
@@ -143,6 +216,25 @@ impl CompactionProfile {
 	}
 }
 
+/// Zstd dictionary compression settings for a column.
+///
+/// Columns holding many small, similar values compress much better with a
+/// dictionary trained on samples of their data.
+/// More info: https://github.com/facebook/rocksdb/wiki/Dictionary-Compression
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ZstdDictionaryConfig {
+	/// Maximum size (in bytes) of the trained dictionary.
+	pub max_dict_bytes: i32,
+	/// Maximum size (in bytes) of the sample data passed to the zstd dictionary trainer.
+	pub max_train_bytes: i32,
+}
+
+impl Default for ZstdDictionaryConfig {
+	fn default() -> ZstdDictionaryConfig {
+		ZstdDictionaryConfig { max_dict_bytes: 16 * KB as i32, max_train_bytes: 100 * 16 * KB as i32 }
+	}
+}
+
 /// Database configuration
 #[derive(Clone)]
 #[non_exhaustive]
@@ -184,9 +276,34 @@ pub struct DatabaseConfig {
 	/// Limit the size (in bytes) of write ahead logs
 	/// More info: https://github.com/facebook/rocksdb/wiki/Write-Ahead-Log
 	pub max_total_wal_size: Option<u64>,
+	/// Limit the total size (in bytes) of the memtables across all columns.
+	/// Uses the RocksDB default (unlimited) when `None`.
+	pub db_write_buffer_size: Option<usize>,
 	/// Creates a new database if no database exists.
 	/// Set to `true` by default for backwards compatibility.
 	pub create_if_missing: bool,
+	/// Columns compressed with zstd using a trained dictionary instead of Snappy.
+	/// Empty by default.
+	pub zstd_dictionaries: HashMap<u32, ZstdDictionaryConfig>,
+	/// Names of the column families, one per column.
+	/// When `None`, columns are named `col0`, `col1`, and so on.
+	///
+	/// Columns added with [`Database::add_column`] past the end of the list
+	/// fall back to the `col{N}` naming scheme.
+	pub column_names: Option<Vec<String>>,
+	/// Skip the write ahead log for all writes.
+	/// Disabled by default.
+	///
+	/// Speeds up bulk imports, but writes that have not been flushed to disk yet
+	/// are lost if the process crashes. Use [`Database::write_with_opts`] to
+	/// write with the WAL enabled again, or flush once the import is done.
+	pub disable_wal: bool,
+	/// Verify the checksums of the blocks read from disk.
+	/// Disabled by default for performance, see [`Database::get_verified`] for one-off checks.
+	pub verify_checksums: bool,
+	/// Do not use a block cache, regardless of the memory budget.
+	/// Disabled by default.
+	pub disable_block_cache: bool,
 }
 
 impl DatabaseConfig {
@@ -202,6 +319,27 @@ impl DatabaseConfig {
 		Self { columns, ..Default::default() }
 	}
 
+	/// Create a `DatabaseConfig` with the specified set of columns, meant for tests only.
+	///
+	/// The block cache is disabled, every column gets a small memtable budget
+	/// and only a few files are kept open, so that short-lived databases are cheap to create.
+	///
+	/// # Panics
+	///
+	/// The number of `columns` must not be zero.
+	pub fn test_config(columns: u32) -> Self {
+		assert!(columns > 0, "the number of columns must not be zero");
+
+		Self {
+			columns,
+			max_open_files: 16,
+			memory_budget: (0..columns).map(|col| (col, 1)).collect(),
+			keep_log_file_num: 1,
+			disable_block_cache: true,
+			..Default::default()
+		}
+	}
+
 	/// Returns the total memory budget in bytes.
 	pub fn memory_budget(&self) -> MiB {
 		(0..self.columns)
@@ -209,6 +347,14 @@ impl DatabaseConfig {
 			.sum()
 	}
 
+	/// Returns the name of the column family of the specified column.
+	fn column_name(&self, col: u32) -> String {
+		match self.column_names.as_ref().and_then(|names| names.get(col as usize)) {
+			Some(name) => name.clone(),
+			None => format!("col{}", col),
+		}
+	}
+
 	/// Returns the memory budget of the specified column in bytes.
 	fn memory_budget_for_col(&self, col: u32) -> MiB {
 		self.memory_budget.get(&col).unwrap_or(&DB_DEFAULT_COLUMN_MEMORY_BUDGET_MB) * MB
@@ -224,6 +370,19 @@ impl DatabaseConfig {
 		opts.optimize_level_style_compaction(column_mem_budget);
 		opts.set_target_file_size_base(self.compaction.initial_file_size);
 		opts.set_compression_per_level(&[]);
+		if let Some(dictionary) = self.zstd_dictionaries.get(&col) {
+			// Default window bits, level and strategy of RocksDB.
+			const WINDOW_BITS: i32 = -14;
+			const LEVEL: i32 = 32767;
+			const STRATEGY: i32 = 0;
+
+			opts.set_compression_type(DBCompressionType::Zstd);
+			opts.set_compression_options(WINDOW_BITS, LEVEL, STRATEGY, dictionary.max_dict_bytes);
+			opts.set_zstd_max_train_bytes(dictionary.max_train_bytes);
+			opts.set_bottommost_compression_type(DBCompressionType::Zstd);
+			opts.set_bottommost_compression_options(WINDOW_BITS, LEVEL, STRATEGY, dictionary.max_dict_bytes, true);
+			opts.set_bottommost_zstd_max_train_bytes(dictionary.max_train_bytes, true);
+		}
 
 		opts
 	}
@@ -240,11 +399,40 @@ impl Default for DatabaseConfig {
 			enable_statistics: false,
 			secondary: None,
 			max_total_wal_size: None,
+			db_write_buffer_size: None,
 			create_if_missing: true,
+			zstd_dictionaries: HashMap::new(),
+			column_names: None,
+			disable_wal: false,
+			verify_checksums: false,
+			disable_block_cache: false,
 		}
 	}
 }
 
+/// Per-write options, see [`Database::write_with_opts`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WriteOpts {
+	/// Sync the write ahead log to disk before the write returns.
+	///
+	/// Makes the write survive a machine crash, not only a process crash, at the cost of
+	/// an `fsync` per write. Has no effect if `disable_wal` is set.
+	pub sync: bool,
+	/// Skip the write ahead log for this write.
+	///
+	/// The write is lost on crash until the affected columns are flushed to disk.
+	pub disable_wal: bool,
+}
+
+impl From<WriteOpts> for WriteOptions {
+	fn from(opts: WriteOpts) -> Self {
+		let mut write_opts = WriteOptions::default();
+		write_opts.set_sync(opts.sync);
+		write_opts.disable_wal(opts.disable_wal);
+		write_opts
+	}
+}
+
 struct DBAndColumns {
 	db: DB,
 	column_names: Vec<String>,
@@ -259,15 +447,34 @@ impl DBAndColumns {
 	}
 }
 
-/// Key-Value database.
-pub struct Database {
+/// The part of a [`Database`] that is shared with all of its [`DatabaseHandle`]s.
+struct Shared {
 	inner: DBAndColumns,
-	config: DatabaseConfig,
 	opts: Options,
 	write_opts: WriteOptions,
 	read_opts: ReadOptions,
-	block_opts: BlockBasedOptions,
+	verify_checksums: bool,
 	stats: stats::RunningDbStats,
+	// Held for reading by writers and for writing by `flush_and_stats`,
+	// so that no write can land between a flush and the stats sampling following it.
+	flush_lock: RwLock<()>,
+}
+
+/// Key-Value database.
+pub struct Database {
+	shared: Arc<Shared>,
+	config: DatabaseConfig,
+	block_opts: BlockBasedOptions,
+}
+
+/// A cheap, shareable handle to a [`Database`].
+///
+/// All handles obtained through [`Database::handle`] operate on the same underlying
+/// RocksDB instance, so writes made through one are immediately visible to the others.
+/// The instance is closed only once the `Database` and all of its handles are dropped.
+#[derive(Clone)]
+pub struct DatabaseHandle {
+	shared: Arc<Shared>,
 }
 
 /// Generate the options for RocksDB, based on the given `DatabaseConfig`.
@@ -291,13 +498,16 @@ fn generate_options(config: &DatabaseConfig) -> Options {
 	if let Some(m) = config.max_total_wal_size {
 		opts.set_max_total_wal_size(m);
 	}
+	if let Some(size) = config.db_write_buffer_size {
+		opts.set_db_write_buffer_size(size);
+	}
 
 	opts
 }
 
-fn generate_read_options() -> ReadOptions {
+fn generate_read_options(verify_checksums: bool) -> ReadOptions {
 	let mut read_opts = ReadOptions::default();
-	read_opts.set_verify_checksums(false);
+	read_opts.set_verify_checksums(verify_checksums);
 	read_opts
 }
 
@@ -311,7 +521,7 @@ fn generate_block_based_options(config: &DatabaseConfig) -> io::Result<BlockBase
 	// Set cache size as recommended by
 	// https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
 	let cache_size = config.memory_budget() / 3;
-	if cache_size == 0 {
+	if config.disable_block_cache || cache_size == 0 {
 		block_opts.disable_cache()
 	} else {
 		let cache = rocksdb::Cache::new_lru_cache(cache_size);
@@ -327,6 +537,182 @@ fn generate_block_based_options(config: &DatabaseConfig) -> io::Result<BlockBase
 	Ok(block_opts)
 }
 
+impl Shared {
+	fn write(&self, tr: DBTransaction) -> io::Result<()> {
+		self.write_opt(tr, &self.write_opts)
+	}
+
+	fn write_opt(&self, tr: DBTransaction, write_opts: &WriteOptions) -> io::Result<()> {
+		let _flush_guard = self.flush_lock.read();
+		self.write_locked(tr, write_opts)
+	}
+
+	/// Write `tr` to the database, the caller must hold `flush_lock`.
+	fn write_locked(&self, tr: DBTransaction, write_opts: &WriteOptions) -> io::Result<()> {
+		let cfs = &self.inner;
+		let mut batch = WriteBatch::default();
+		let ops = tr.ops;
+
+		self.stats.tally_writes(ops.len() as u64);
+		self.stats.tally_transactions(1);
+
+		let mut stats_total_bytes = 0;
+
+		for op in ops {
+			let col = op.col();
+			let cf = cfs.cf(col as usize)?;
+
+			match op {
+				DBOp::Insert { col: _, key, value } => {
+					stats_total_bytes += key.len() + value.len();
+					batch.put_cf(cf, &key, &value);
+				},
+				DBOp::Delete { col: _, key } => {
+					// We count deletes as writes.
+					stats_total_bytes += key.len();
+					batch.delete_cf(cf, &key);
+				},
+				DBOp::DeletePrefix { col, prefix } => {
+					let end_prefix = kvdb::end_prefix(&prefix[..]);
+					let no_end = end_prefix.is_none();
+					let end_range = end_prefix.unwrap_or_else(|| vec![u8::max_value(); 16]);
+					batch.delete_range_cf(cf, &prefix[..], &end_range[..]);
+					if no_end {
+						let prefix = if prefix.len() > end_range.len() { &prefix[..] } else { &end_range[..] };
+						for result in self.iter_with_prefix(col, prefix) {
+							let (key, _) = result?;
+							batch.delete_cf(cf, &key[..]);
+						}
+					}
+				},
+			};
+		}
+		self.stats.tally_bytes_written(stats_total_bytes as u64);
+
+		cfs.db.write_opt(batch, write_opts).map_err(rocksdb_io_err)
+	}
+
+	fn get(&self, col: u32, key: &[u8]) -> io::Result<Option<DBValue>> {
+		self.get_opt(col, key, &self.read_opts)
+	}
+
+	fn get_opt(&self, col: u32, key: &[u8], read_opts: &ReadOptions) -> io::Result<Option<DBValue>> {
+		let cfs = &self.inner;
+		let cf = cfs.cf(col as usize)?;
+		self.stats.tally_reads(1);
+		let value = cfs
+			.db
+			.get_pinned_cf_opt(cf, key, read_opts)
+			.map(|r| r.map(|v| v.to_vec()))
+			.map_err(rocksdb_io_err);
+
+		match value {
+			Ok(Some(ref v)) => self.stats.tally_bytes_read((key.len() + v.len()) as u64),
+			Ok(None) => self.stats.tally_bytes_read(key.len() as u64),
+			_ => {},
+		};
+
+		value
+	}
+
+	fn put_if_absent(&self, col: u32, key: &[u8], value: &[u8]) -> io::Result<bool> {
+		// Holding the lock for writing keeps any other write from landing between the check and
+		// the insert.
+		let _write_guard = self.flush_lock.write();
+		if self.get(col, key)?.is_some() {
+			return Ok(false)
+		}
+		let mut transaction = DBTransaction::new();
+		transaction.put(col, key, value);
+		self.write_locked(transaction, &self.write_opts)?;
+		Ok(true)
+	}
+
+	fn has_keys(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<bool>> {
+		let cfs = &self.inner;
+		let cf = cfs.cf(col as usize)?;
+		keys.iter()
+			.map(|key| {
+				// `key_may_exist` can report false positives, but never false negatives.
+				if !cfs.db.key_may_exist_cf_opt(cf, key, &self.read_opts) {
+					return Ok(false)
+				}
+				self.get(col, key).map(|value| value.is_some())
+			})
+			.collect()
+	}
+
+	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
+		self.iter_with_prefix(col, prefix)
+			.next()
+			.transpose()
+			.map(|m| m.map(|(_k, v)| v))
+	}
+
+	fn iter<'a>(&'a self, col: u32) -> impl Iterator<Item = io::Result<DBKeyValue>> + 'a {
+		let read_opts = generate_read_options(self.verify_checksums);
+		iter::IterationHandler::iter(&self.inner, col, read_opts)
+	}
+
+	fn iter_with_prefix<'a>(&'a self, col: u32, prefix: &'a [u8]) -> impl Iterator<Item = io::Result<DBKeyValue>> + 'a {
+		let mut read_opts = generate_read_options(self.verify_checksums);
+		// rocksdb doesn't work with an empty upper bound
+		if let Some(end_prefix) = kvdb::end_prefix(prefix) {
+			read_opts.set_iterate_upper_bound(end_prefix);
+		}
+		iter::IterationHandler::iter_with_prefix(&self.inner, col, prefix, read_opts)
+	}
+
+	fn flush(&self) -> io::Result<()> {
+		let cfs = &self.inner;
+		for i in 0..cfs.column_names.len() {
+			cfs.db.flush_cf(cfs.cf(i)?).map_err(rocksdb_io_err)?;
+		}
+		Ok(())
+	}
+
+	fn flush_and_stats(&self, kind: kvdb::IoStatsKind) -> io::Result<kvdb::IoStats> {
+		let _flush_guard = self.flush_lock.write();
+		self.flush()?;
+		Ok(self.io_stats(kind))
+	}
+
+	fn get_statistics(&self) -> HashMap<String, stats::RocksDbStatsValue> {
+		if let Some(stats) = self.opts.get_statistics() {
+			stats::parse_rocksdb_stats(&stats)
+		} else {
+			HashMap::new()
+		}
+	}
+
+	fn io_stats(&self, kind: kvdb::IoStatsKind) -> kvdb::IoStats {
+		let rocksdb_stats = self.get_statistics();
+		let cache_hit_count = rocksdb_stats.get("block.cache.hit").map(|s| s.count).unwrap_or(0u64);
+		let overall_stats = self.stats.overall();
+		let old_cache_hit_count = overall_stats.raw.cache_hit_count;
+
+		self.stats.tally_cache_hit_count(cache_hit_count - old_cache_hit_count);
+
+		let taken_stats = match kind {
+			kvdb::IoStatsKind::Overall => self.stats.overall(),
+			kvdb::IoStatsKind::SincePrevious => self.stats.since_previous(),
+		};
+
+		let mut stats = kvdb::IoStats::empty();
+
+		stats.reads = taken_stats.raw.reads;
+		stats.writes = taken_stats.raw.writes;
+		stats.transactions = taken_stats.raw.transactions;
+		stats.bytes_written = taken_stats.raw.bytes_written;
+		stats.bytes_read = taken_stats.raw.bytes_read;
+		stats.cache_reads = taken_stats.raw.cache_hit_count;
+		stats.started = taken_stats.started;
+		stats.span = taken_stats.started.elapsed();
+
+		stats
+	}
+}
+
 impl Database {
 	/// Open database file.
 	///
@@ -335,13 +721,19 @@ impl Database {
 	/// The number of `config.columns` must not be zero.
 	pub fn open<P: AsRef<Path>>(config: &DatabaseConfig, path: P) -> io::Result<Database> {
 		assert!(config.columns > 0, "the number of columns must not be zero");
+		if let Some(names) = &config.column_names {
+			if names.len() != config.columns as usize {
+				return Err(other_io_err(format!("expected {} column names, got {}", config.columns, names.len())))
+			}
+		}
 
 		let opts = generate_options(config);
 		let block_opts = generate_block_based_options(config)?;
 
-		let column_names: Vec<_> = (0..config.columns).map(|c| format!("col{}", c)).collect();
-		let write_opts = WriteOptions::default();
-		let read_opts = generate_read_options();
+		let column_names: Vec<_> = (0..config.columns).map(|c| config.column_name(c)).collect();
+		let mut write_opts = WriteOptions::default();
+		write_opts.disable_wal(config.disable_wal);
+		let read_opts = generate_read_options(config.verify_checksums);
 
 		let db = if let Some(secondary_path) = &config.secondary {
 			Self::open_secondary(&opts, path.as_ref(), secondary_path.as_ref(), column_names.as_slice())?
@@ -351,13 +743,17 @@ impl Database {
 		};
 
 		Ok(Database {
-			inner: DBAndColumns { db, column_names },
+			shared: Arc::new(Shared {
+				inner: DBAndColumns { db, column_names },
+				opts,
+				read_opts,
+				verify_checksums: config.verify_checksums,
+				write_opts,
+				stats: stats::RunningDbStats::new(),
+				flush_lock: RwLock::new(()),
+			}),
 			config: config.clone(),
-			opts,
-			read_opts,
-			write_opts,
 			block_opts,
-			stats: stats::RunningDbStats::new(),
 		})
 	}
 
@@ -381,7 +777,7 @@ impl Database {
 						for (i, name) in column_names.iter().enumerate() {
 							let _ = db
 								.create_cf(name, &config.column_config(&block_opts, i as u32))
-								.map_err(other_io_err)?;
+								.map_err(rocksdb_io_err)?;
 						}
 						Ok(db)
 					},
@@ -393,7 +789,7 @@ impl Database {
 
 		Ok(match db {
 			Ok(db) => db,
-			Err(s) => return Err(other_io_err(s)),
+			Err(s) => return Err(rocksdb_io_err(s)),
 		})
 	}
 
@@ -409,10 +805,15 @@ impl Database {
 
 		Ok(match db {
 			Ok(db) => db,
-			Err(s) => return Err(other_io_err(s)),
+			Err(s) => return Err(rocksdb_io_err(s)),
 		})
 	}
 
+	/// Get a new handle sharing the underlying RocksDB instance with this database.
+	pub fn handle(&self) -> DatabaseHandle {
+		DatabaseHandle { shared: self.shared.clone() }
+	}
+
 	/// Helper to create new transaction for this database.
 	pub fn transaction(&self) -> DBTransaction {
 		DBTransaction::new()
@@ -420,140 +821,113 @@ impl Database {
 
 	/// Commit transaction to database.
 	pub fn write(&self, tr: DBTransaction) -> io::Result<()> {
-		let cfs = &self.inner;
-		let mut batch = WriteBatch::default();
-		let ops = tr.ops;
-
-		self.stats.tally_writes(ops.len() as u64);
-		self.stats.tally_transactions(1);
-
-		let mut stats_total_bytes = 0;
-
-		for op in ops {
-			let col = op.col();
-			let cf = cfs.cf(col as usize)?;
-
-			match op {
-				DBOp::Insert { col: _, key, value } => {
-					stats_total_bytes += key.len() + value.len();
-					batch.put_cf(cf, &key, &value);
-				},
-				DBOp::Delete { col: _, key } => {
-					// We count deletes as writes.
-					stats_total_bytes += key.len();
-					batch.delete_cf(cf, &key);
-				},
-				DBOp::DeletePrefix { col, prefix } => {
-					let end_prefix = kvdb::end_prefix(&prefix[..]);
-					let no_end = end_prefix.is_none();
-					let end_range = end_prefix.unwrap_or_else(|| vec![u8::max_value(); 16]);
-					batch.delete_range_cf(cf, &prefix[..], &end_range[..]);
-					if no_end {
-						let prefix = if prefix.len() > end_range.len() { &prefix[..] } else { &end_range[..] };
-						for result in self.iter_with_prefix(col, prefix) {
-							let (key, _) = result?;
-							batch.delete_cf(cf, &key[..]);
-						}
-					}
-				},
-			};
-		}
-		self.stats.tally_bytes_written(stats_total_bytes as u64);
+		self.shared.write(tr)
+	}
 
-		cfs.db.write_opt(batch, &self.write_opts).map_err(other_io_err)
+	/// Commit transaction to database with the given per-write options,
+	/// overriding `DatabaseConfig::disable_wal` for this write.
+	pub fn write_with_opts(&self, tr: DBTransaction, opts: WriteOpts) -> io::Result<()> {
+		self.shared.write_opt(tr, &opts.into())
 	}
 
 	/// Get value by key.
 	pub fn get(&self, col: u32, key: &[u8]) -> io::Result<Option<DBValue>> {
-		let cfs = &self.inner;
-		let cf = cfs.cf(col as usize)?;
-		self.stats.tally_reads(1);
-		let value = cfs
-			.db
-			.get_pinned_cf_opt(cf, key, &self.read_opts)
-			.map(|r| r.map(|v| v.to_vec()))
-			.map_err(other_io_err);
-
-		match value {
-			Ok(Some(ref v)) => self.stats.tally_bytes_read((key.len() + v.len()) as u64),
-			Ok(None) => self.stats.tally_bytes_read(key.len() as u64),
-			_ => {},
-		};
+		self.shared.get(col, key)
+	}
 
-		value
+	/// Get value by key, verifying the checksums of the blocks read
+	/// regardless of `DatabaseConfig::verify_checksums`.
+	pub fn get_verified(&self, col: u32, key: &[u8]) -> io::Result<Option<DBValue>> {
+		self.shared.get_opt(col, key, &generate_read_options(true))
 	}
 
 	/// Get value by partial key. Prefix size should match configured prefix size.
 	pub fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
-		self.iter_with_prefix(col, prefix)
-			.next()
-			.transpose()
-			.map(|m| m.map(|(_k, v)| v))
+		self.shared.get_by_prefix(col, prefix)
 	}
 
 	/// Iterator over the data in the given database column index.
 	/// Will hold a lock until the iterator is dropped
 	/// preventing the database from being closed.
 	pub fn iter<'a>(&'a self, col: u32) -> impl Iterator<Item = io::Result<DBKeyValue>> + 'a {
-		let read_opts = generate_read_options();
-		iter::IterationHandler::iter(&self.inner, col, read_opts)
+		self.shared.iter(col)
 	}
 
 	/// Iterator over data in the `col` database column index matching the given prefix.
 	/// Will hold a lock until the iterator is dropped
 	/// preventing the database from being closed.
 	fn iter_with_prefix<'a>(&'a self, col: u32, prefix: &'a [u8]) -> impl Iterator<Item = io::Result<DBKeyValue>> + 'a {
-		let mut read_opts = generate_read_options();
-		// rocksdb doesn't work with an empty upper bound
-		if let Some(end_prefix) = kvdb::end_prefix(prefix) {
-			read_opts.set_iterate_upper_bound(end_prefix);
-		}
-		iter::IterationHandler::iter_with_prefix(&self.inner, col, prefix, read_opts)
+		self.shared.iter_with_prefix(col, prefix)
 	}
 
 	/// The number of column families in the db.
 	pub fn num_columns(&self) -> u32 {
-		self.inner.column_names.len() as u32
+		self.shared.inner.column_names.len() as u32
 	}
 
 	/// The number of keys in a column (estimated).
 	pub fn num_keys(&self, col: u32) -> io::Result<u64> {
 		const ESTIMATE_NUM_KEYS: &str = "rocksdb.estimate-num-keys";
-		let cfs = &self.inner;
+		let cfs = &self.shared.inner;
 		let cf = cfs.cf(col as usize)?;
 		match cfs.db.property_int_value_cf(cf, ESTIMATE_NUM_KEYS) {
 			Ok(estimate) => Ok(estimate.unwrap_or_default()),
-			Err(err_string) => Err(other_io_err(err_string)),
+			Err(err_string) => Err(rocksdb_io_err(err_string)),
 		}
 	}
 
+	/// Get exclusive access to the underlying database.
+	/// Fails if any [`DatabaseHandle`] to it is still alive.
+	fn inner_mut(&mut self) -> io::Result<&mut DBAndColumns> {
+		Arc::get_mut(&mut self.shared)
+			.map(|shared| &mut shared.inner)
+			.ok_or_else(|| other_io_err("cannot modify columns while database handles are alive"))
+	}
+
 	/// Remove the last column family in the database. The deletion is definitive.
+	///
+	/// Fails if any [`DatabaseHandle`] to this database is still alive.
 	pub fn remove_last_column(&mut self) -> io::Result<()> {
-		let DBAndColumns { ref mut db, ref mut column_names } = self.inner;
+		let DBAndColumns { db, column_names } = self.inner_mut()?;
 		if let Some(name) = column_names.pop() {
-			db.drop_cf(&name).map_err(other_io_err)?;
+			db.drop_cf(&name).map_err(rocksdb_io_err)?;
 		}
 		Ok(())
 	}
 
 	/// Add a new column family to the DB.
+	///
+	/// Fails if any [`DatabaseHandle`] to this database is still alive.
 	pub fn add_column(&mut self) -> io::Result<()> {
-		let DBAndColumns { ref mut db, ref mut column_names } = self.inner;
-		let col = column_names.len() as u32;
-		let name = format!("col{}", col);
-		let col_config = self.config.column_config(&self.block_opts, col as u32);
-		let _ = db.create_cf(&name, &col_config).map_err(other_io_err)?;
+		let col = self.num_columns();
+		let name = self.config.column_name(col);
+		let col_config = self.config.column_config(&self.block_opts, col);
+		let DBAndColumns { db, column_names } = self.inner_mut()?;
+		let _ = db.create_cf(&name, &col_config).map_err(rocksdb_io_err)?;
 		column_names.push(name);
 		Ok(())
 	}
 
+	/// Flush the memtables of all columns to disk and then sample the IO statistics.
+	///
+	/// Writes are blocked for the duration of the call, so the returned statistics
+	/// account for exactly the writes that were flushed.
+	pub fn flush_and_stats(&self, kind: kvdb::IoStatsKind) -> io::Result<kvdb::IoStats> {
+		self.shared.flush_and_stats(kind)
+	}
+
+	/// Write the RocksDB write-ahead log to disk and fsync it.
+	///
+	/// Unlike [`Database::flush_and_stats`], which flushes the memtables into SST files, this
+	/// only makes the writes committed so far durable against a crash, without touching the
+	/// memtables. Writes made with the WAL disabled are not covered.
+	pub fn sync_wal(&self) -> io::Result<()> {
+		self.shared.inner.db.flush_wal(true).map_err(rocksdb_io_err)
+	}
+
 	/// Get RocksDB statistics.
 	pub fn get_statistics(&self) -> HashMap<String, stats::RocksDbStatsValue> {
-		if let Some(stats) = self.opts.get_statistics() {
-			stats::parse_rocksdb_stats(&stats)
-		} else {
-			HashMap::new()
-		}
+		self.shared.get_statistics()
 	}
 
 	/// Try to catch up a secondary instance with
@@ -577,7 +951,7 @@ impl Database {
 	///
 	/// Calling this as primary will return an error.
 	pub fn try_catch_up_with_primary(&self) -> io::Result<()> {
-		self.inner.db.try_catch_up_with_primary().map_err(other_io_err)
+		self.shared.inner.db.try_catch_up_with_primary().map_err(rocksdb_io_err)
 	}
 }
 
@@ -588,6 +962,14 @@ impl KeyValueDB for Database {
 		Database::get(self, col, key)
 	}
 
+	fn has_keys(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<bool>> {
+		self.shared.has_keys(col, keys)
+	}
+
+	fn put_if_absent(&self, col: u32, key: &[u8], value: &[u8]) -> io::Result<bool> {
+		self.shared.put_if_absent(col, key, value)
+	}
+
 	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
 		Database::get_by_prefix(self, col, prefix)
 	}
@@ -611,30 +993,45 @@ impl KeyValueDB for Database {
 	}
 
 	fn io_stats(&self, kind: kvdb::IoStatsKind) -> kvdb::IoStats {
-		let rocksdb_stats = self.get_statistics();
-		let cache_hit_count = rocksdb_stats.get("block.cache.hit").map(|s| s.count).unwrap_or(0u64);
-		let overall_stats = self.stats.overall();
-		let old_cache_hit_count = overall_stats.raw.cache_hit_count;
+		self.shared.io_stats(kind)
+	}
+}
 
-		self.stats.tally_cache_hit_count(cache_hit_count - old_cache_hit_count);
+impl KeyValueDB for DatabaseHandle {
+	fn get(&self, col: u32, key: &[u8]) -> io::Result<Option<DBValue>> {
+		self.shared.get(col, key)
+	}
 
-		let taken_stats = match kind {
-			kvdb::IoStatsKind::Overall => self.stats.overall(),
-			kvdb::IoStatsKind::SincePrevious => self.stats.since_previous(),
-		};
+	fn has_keys(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<bool>> {
+		self.shared.has_keys(col, keys)
+	}
 
-		let mut stats = kvdb::IoStats::empty();
+	fn put_if_absent(&self, col: u32, key: &[u8], value: &[u8]) -> io::Result<bool> {
+		self.shared.put_if_absent(col, key, value)
+	}
 
-		stats.reads = taken_stats.raw.reads;
-		stats.writes = taken_stats.raw.writes;
-		stats.transactions = taken_stats.raw.transactions;
-		stats.bytes_written = taken_stats.raw.bytes_written;
-		stats.bytes_read = taken_stats.raw.bytes_read;
-		stats.cache_reads = taken_stats.raw.cache_hit_count;
-		stats.started = taken_stats.started;
-		stats.span = taken_stats.started.elapsed();
+	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
+		self.shared.get_by_prefix(col, prefix)
+	}
 
-		stats
+	fn write(&self, transaction: DBTransaction) -> io::Result<()> {
+		self.shared.write(transaction)
+	}
+
+	fn iter<'a>(&'a self, col: u32) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		Box::new(self.shared.iter(col))
+	}
+
+	fn iter_with_prefix<'a>(
+		&'a self,
+		col: u32,
+		prefix: &'a [u8],
+	) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		Box::new(self.shared.iter_with_prefix(col, prefix))
+	}
+
+	fn io_stats(&self, kind: kvdb::IoStatsKind) -> kvdb::IoStats {
+		self.shared.io_stats(kind)
 	}
 }
 
@@ -663,6 +1060,50 @@ mod tests {
 		st::test_put_and_get(&db)
 	}
 
+	#[test]
+	fn has_keys() -> io::Result<()> {
+		let db = create(1)?;
+		st::test_has_keys(&db)
+	}
+
+	#[test]
+	fn put_if_absent() -> io::Result<()> {
+		let db = create(1)?;
+		st::test_put_if_absent(&db)
+	}
+
+	#[test]
+	fn put_if_absent_with_racing_writers() -> io::Result<()> {
+		let db = create(1)?;
+		for round in 0u8..10 {
+			let key = [round];
+			let writers: Vec<_> = (0u8..8)
+				.map(|writer| {
+					let handle = db.handle();
+					std::thread::spawn(move || {
+						if writer % 2 == 0 {
+							handle.put_if_absent(0, &key, &[writer]).map(|written| written.then(|| writer))
+						} else {
+							let mut transaction = handle.transaction();
+							transaction.put(0, &[round, writer], &[writer]);
+							handle.write(transaction).map(|()| None)
+						}
+					})
+				})
+				.collect();
+			let winners: Vec<u8> = writers
+				.into_iter()
+				.map(|writer| writer.join().expect("writer thread does not panic"))
+				.collect::<io::Result<Vec<_>>>()?
+				.into_iter()
+				.flatten()
+				.collect();
+			assert_eq!(winners.len(), 1);
+			assert_eq!(db.get(0, &key)?.unwrap(), vec![winners[0]]);
+		}
+		Ok(())
+	}
+
 	#[test]
 	fn delete_and_get() -> io::Result<()> {
 		let db = create(1)?;
@@ -737,6 +1178,172 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn zstd_dictionary_settings() {
+		let dictionary = ZstdDictionaryConfig { max_dict_bytes: 4 * KB as i32, max_train_bytes: 400 * KB as i32 };
+		let mut cfg = DatabaseConfig::with_columns(2);
+		cfg.zstd_dictionaries = [(1, dictionary)].iter().cloned().collect();
+
+		let db_path = TempfileBuilder::new()
+			.prefix("zstd_dictionary")
+			.tempdir()
+			.expect("the OS can create tmp dirs");
+		let db = Database::open(&cfg, db_path.path()).expect("can open a db");
+		drop(db);
+
+		let mut rocksdb_log = std::fs::File::open(format!("{}/LOG", db_path.path().to_str().unwrap()))
+			.expect("rocksdb creates a LOG file");
+		let mut settings = String::new();
+		rocksdb_log.read_to_string(&mut settings).unwrap();
+
+		// Only `col1` is configured with zstd, `default` and `col0` keep Snappy.
+		let col1 = settings
+			.split("Options for column family [col1]")
+			.nth(1)
+			.expect("col1 options are logged");
+		assert!(col1.contains("Options.compression: ZSTD"));
+		assert!(col1.contains(&format!("Options.compression_opts.max_dict_bytes: {}", 4 * KB)));
+		assert!(col1.contains(&format!("Options.compression_opts.zstd_max_train_bytes: {}", 400 * KB)));
+		let zstd_compression = settings.matches("Options.compression: ZSTD").count();
+		assert_eq!(zstd_compression, 1);
+	}
+
+	#[test]
+	fn test_config() -> io::Result<()> {
+		let cfg = DatabaseConfig::test_config(2);
+		assert_eq!(cfg.memory_budget(), 2 * MB);
+
+		let db_path = TempfileBuilder::new().prefix("test_config").tempdir()?;
+		let db = Database::open(&cfg, db_path.path())?;
+		let mut transaction = db.transaction();
+		transaction.put(1, b"key1", b"horse");
+		db.write(transaction)?;
+		assert_eq!(&*db.get(1, b"key1")?.unwrap(), b"horse");
+		drop(db);
+
+		let mut rocksdb_log = std::fs::File::open(format!("{}/LOG", db_path.path().to_str().unwrap()))?;
+		let mut settings = String::new();
+		rocksdb_log.read_to_string(&mut settings)?;
+		assert!(settings.contains("no_block_cache: 1"));
+		assert!(settings.contains("Options.max_open_files: 16"));
+		assert!(settings.contains(&format!("Options.max_bytes_for_level_base: {}", MB)));
+		Ok(())
+	}
+
+	#[test]
+	fn bulk_import_without_wal() -> io::Result<()> {
+		let config = DatabaseConfig { disable_wal: true, ..DatabaseConfig::with_columns(1) };
+		let tempdir = TempfileBuilder::new().prefix("bulk_import_without_wal").tempdir()?;
+
+		{
+			let db = Database::open(&config, tempdir.path())?;
+			for chunk in 0u32..10 {
+				let mut transaction = db.transaction();
+				for i in chunk * 100..(chunk + 1) * 100 {
+					transaction.put(0, &i.to_be_bytes(), b"imported");
+				}
+				db.write(transaction)?;
+			}
+
+			let mut transaction = db.transaction();
+			transaction.put(0, b"done", b"yes");
+			db.write_with_opts(transaction, WriteOpts { sync: true, disable_wal: false })?;
+			db.flush_and_stats(kvdb::IoStatsKind::Overall)?;
+		}
+
+		let db = Database::open(&DatabaseConfig::with_columns(1), tempdir.path())?;
+		assert_eq!(db.iter(0).count(), 1001);
+		assert_eq!(&*db.get(0, &999u32.to_be_bytes())?.unwrap(), b"imported");
+		assert_eq!(&*db.get(0, b"done")?.unwrap(), b"yes");
+		Ok(())
+	}
+
+	#[test]
+	fn verify_checksums() -> io::Result<()> {
+		let tempdir = TempfileBuilder::new().prefix("verify_checksums").tempdir()?;
+		for verify_checksums in [false, true] {
+			let config = DatabaseConfig { verify_checksums, ..DatabaseConfig::with_columns(1) };
+			let db = Database::open(&config, tempdir.path())?;
+			let mut transaction = db.transaction();
+			transaction.put(0, b"key", b"value");
+			db.write(transaction)?;
+			db.flush_and_stats(kvdb::IoStatsKind::Overall)?;
+
+			assert_eq!(&*db.get(0, b"key")?.unwrap(), b"value");
+			assert_eq!(&*db.get_verified(0, b"key")?.unwrap(), b"value");
+			assert_eq!(db.get_verified(0, b"missing")?, None);
+			assert_eq!(db.iter(0).count(), 1);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn sync_wal() -> io::Result<()> {
+		let config = DatabaseConfig::with_columns(1);
+		let tempdir = TempfileBuilder::new().prefix("sync_wal").tempdir()?;
+
+		{
+			let db = Database::open(&config, tempdir.path())?;
+			let mut transaction = db.transaction();
+			transaction.put(0, b"key", b"value");
+			db.write(transaction)?;
+			db.sync_wal()?;
+		}
+
+		let db = Database::open(&config, tempdir.path())?;
+		assert_eq!(&*db.get(0, b"key")?.unwrap(), b"value");
+		Ok(())
+	}
+
+	#[test]
+	fn flush_and_stats() -> io::Result<()> {
+		let db = create(2)?;
+		let mut transaction = db.transaction();
+		transaction.put(0, b"key1", b"horse");
+		transaction.put(1, b"key2", b"cat");
+		db.write(transaction)?;
+
+		let stats = db.flush_and_stats(kvdb::IoStatsKind::SincePrevious)?;
+		assert_eq!(stats.transactions, 1);
+		assert_eq!(stats.writes, 2);
+		assert_eq!(stats.bytes_written, (b"key1horse".len() + b"key2cat".len()) as u64);
+
+		let stats = db.flush_and_stats(kvdb::IoStatsKind::SincePrevious)?;
+		assert_eq!(stats.writes, 0);
+		assert_eq!(&*db.get(1, b"key2")?.unwrap(), b"cat");
+		Ok(())
+	}
+
+	#[test]
+	fn handles_share_the_database() -> io::Result<()> {
+		let db = create(1)?;
+		let handle_1 = db.handle();
+		let handle_2 = db.handle();
+
+		let mut transaction = handle_1.transaction();
+		transaction.put(0, b"key1", b"horse");
+		handle_1.write(transaction)?;
+		assert_eq!(&*handle_2.get(0, b"key1")?.unwrap(), b"horse");
+
+		let mut transaction = handle_2.transaction();
+		transaction.put(0, b"key2", b"cat");
+		handle_2.write(transaction)?;
+		assert_eq!(&*handle_1.get(0, b"key2")?.unwrap(), b"cat");
+		assert_eq!(&*db.get(0, b"key2")?.unwrap(), b"cat");
+		Ok(())
+	}
+
+	#[test]
+	fn columns_cannot_change_while_handles_are_alive() -> io::Result<()> {
+		let mut db = create(1)?;
+		let handle = db.handle();
+		assert!(db.add_column().is_err());
+		drop(handle);
+		db.add_column()?;
+		assert_eq!(db.num_columns(), 2);
+		Ok(())
+	}
+
 	#[test]
 	#[cfg(target_os = "linux")]
 	fn df_to_rotational() {
@@ -816,6 +1423,73 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn custom_column_names() {
+		let names = vec!["headers".to_owned(), "bodies".to_owned()];
+		let config = DatabaseConfig { column_names: Some(names.clone()), ..DatabaseConfig::with_columns(2) };
+		let tempdir = TempfileBuilder::new().prefix("custom_column_names").tempdir().unwrap();
+
+		{
+			let mut db = Database::open(&config, tempdir.path()).unwrap();
+			assert_eq!(db.shared.inner.column_names, names);
+
+			let mut batch = db.transaction();
+			batch.put(1, b"key", b"value");
+			db.write(batch).unwrap();
+
+			db.remove_last_column().unwrap();
+			db.add_column().unwrap();
+			db.add_column().unwrap();
+			assert_eq!(db.shared.inner.column_names, vec!["headers", "bodies", "col2"]);
+		}
+
+		// The column families on disk are the custom ones.
+		let mut on_disk = DB::list_cf(&Options::default(), tempdir.path()).unwrap();
+		on_disk.sort();
+		assert_eq!(on_disk, vec!["bodies", "col2", "default", "headers"]);
+
+		// Reopen with the same names.
+		let config = DatabaseConfig {
+			column_names: Some(vec!["headers".to_owned(), "bodies".to_owned(), "col2".to_owned()]),
+			..DatabaseConfig::with_columns(3)
+		};
+		let db = Database::open(&config, tempdir.path()).unwrap();
+		assert_eq!(db.num_columns(), 3);
+		assert_eq!(db.get(1, b"key").unwrap(), None);
+	}
+
+	#[test]
+	fn column_names_must_match_columns() {
+		let config = DatabaseConfig { column_names: Some(vec!["only".to_owned()]), ..DatabaseConfig::with_columns(2) };
+		let tempdir = TempfileBuilder::new().prefix("column_names_must_match").tempdir().unwrap();
+		assert!(Database::open(&config, tempdir.path()).is_err());
+	}
+
+	#[test]
+	fn classifies_corruption() {
+		let tempdir = TempfileBuilder::new().prefix("classifies_corruption").tempdir().unwrap();
+		drop(Database::open(&DatabaseConfig::with_columns(1), tempdir.path()).unwrap());
+
+		std::fs::write(tempdir.path().join("CURRENT"), b"garbage").unwrap();
+
+		let err = Database::open(&DatabaseConfig::with_columns(1), tempdir.path()).err().unwrap();
+		let db_err = DbError::from_io_error(&err).expect("rocksdb error is preserved");
+		assert_eq!(db_err.kind(), DbErrorKind::Corruption);
+		assert!(db_err.message().starts_with("Corruption"));
+	}
+
+	#[test]
+	fn classifies_incomplete_column_families() {
+		let tempdir = TempfileBuilder::new().prefix("classifies_incomplete_cfs").tempdir().unwrap();
+		drop(Database::open(&DatabaseConfig::with_columns(2), tempdir.path()).unwrap());
+
+		let err = Database::open(&DatabaseConfig::with_columns(1), tempdir.path()).err().unwrap();
+		let db_err = DbError::from_io_error(&err).expect("rocksdb error is preserved");
+		assert_eq!(db_err.kind(), DbErrorKind::IncompleteColumnFamilies);
+
+		assert!(DbError::from_io_error(&invalid_column(3)).is_none());
+	}
+
 	#[test]
 	fn test_num_keys() {
 		let tempdir = TempfileBuilder::new().prefix("").tempdir().unwrap();
@@ -879,6 +1553,8 @@ rocksdb.db.get.micros P50 : 2.000000 P95 : 3.000000 P99 : 4.000000 P100 : 5.0000
 		cfg.compaction.block_size = 323232;
 		cfg.compaction.initial_file_size = 102030;
 		cfg.memory_budget = [(0, 30), (1, 300)].iter().cloned().collect();
+		cfg.max_total_wal_size = Some(12 * MB as u64);
+		cfg.db_write_buffer_size = Some(34 * MB);
 
 		let db_path = TempfileBuilder::new()
 			.prefix("config_test")
@@ -901,6 +1577,10 @@ rocksdb.db.get.micros P50 : 2.000000 P95 : 3.000000 P99 : 4.000000 P100 : 5.0000
 		// Check max_open_files
 		assert!(settings.contains("max_open_files: 123"));
 
+		// Check WAL and global write buffer limits
+		assert!(settings.contains(&format!("Options.max_total_wal_size: {}", 12 * MB)));
+		assert!(settings.contains(&format!("Options.db_write_buffer_size: {}", 34 * MB)));
+
 		// Check block size
 		assert!(settings.contains(" block_size: 323232"));
 
@@ -38,6 +38,44 @@ pub fn test_delete_and_get(db: &dyn KeyValueDB) -> io::Result<()> {
 	Ok(())
 }
 
+/// A test for `KeyValueDB::has_keys`.
+pub fn test_has_keys(db: &dyn KeyValueDB) -> io::Result<()> {
+	let mut transaction = db.transaction();
+	transaction.put(0, b"key1", b"horse");
+	transaction.put(0, b"key3", b"");
+	db.write(transaction)?;
+
+	let keys: [&[u8]; 5] = [b"key1", b"key2", b"key3", b"key1", b""];
+	assert_eq!(db.has_keys(0, &keys)?, vec![true, false, true, true, false]);
+	assert_eq!(db.has_keys(0, &[])?, Vec::<bool>::new());
+
+	let mut transaction = db.transaction();
+	transaction.delete(0, b"key1");
+	db.write(transaction)?;
+	assert_eq!(db.has_keys(0, &keys[..2])?, vec![false, false]);
+	Ok(())
+}
+
+/// A test for `KeyValueDB::put_if_absent`.
+pub fn test_put_if_absent(db: &dyn KeyValueDB) -> io::Result<()> {
+	assert!(db.put_if_absent(0, b"key1", b"horse")?);
+	assert!(!db.put_if_absent(0, b"key1", b"cow")?);
+	assert_eq!(&*db.get(0, b"key1")?.unwrap(), b"horse");
+
+	let mut transaction = db.transaction();
+	transaction.put(0, b"key2", b"");
+	db.write(transaction)?;
+	assert!(!db.put_if_absent(0, b"key2", b"cow")?);
+	assert_eq!(&*db.get(0, b"key2")?.unwrap(), b"");
+
+	let mut transaction = db.transaction();
+	transaction.delete(0, b"key1");
+	db.write(transaction)?;
+	assert!(db.put_if_absent(0, b"key1", b"cow")?);
+	assert_eq!(&*db.get(0, b"key1")?.unwrap(), b"cow");
+	Ok(())
+}
+
 /// A test for `KeyValueDB::get`.
 /// Assumes the `db` has only 1 column.
 pub fn test_get_fails_with_non_existing_column(db: &dyn KeyValueDB) -> io::Result<()> {
@@ -16,6 +16,10 @@ mod io_stats;
 /// Required length of prefixes.
 pub const PREFIX_LEN: usize = 12;
 
+/// Estimated overhead in bytes of a single operation in a [`DBTransaction`],
+/// on top of its key and value (operation kind, column and length prefixes).
+pub const DB_OP_OVERHEAD: usize = 16;
+
 /// Database value.
 pub type DBValue = Vec<u8>;
 /// Database keys.
@@ -93,6 +97,22 @@ impl DBTransaction {
 	pub fn delete_prefix(&mut self, col: u32, prefix: &[u8]) {
 		self.ops.push(DBOp::DeletePrefix { col, prefix: DBKey::from_slice(prefix) });
 	}
+
+	/// Estimate the size in bytes of this transaction, e.g. to check it against a backend's
+	/// batch size limit before writing it. This is the sum of all keys and values plus
+	/// `DB_OP_OVERHEAD` for every operation.
+	pub fn estimate_size(&self) -> usize {
+		self.ops
+			.iter()
+			.map(|op| {
+				let value_len = match op {
+					DBOp::Insert { value, .. } => value.len(),
+					DBOp::Delete { .. } | DBOp::DeletePrefix { .. } => 0,
+				};
+				DB_OP_OVERHEAD + op.key().len() + value_len
+			})
+			.sum()
+	}
 }
 
 /// Generic key-value database.
@@ -145,6 +165,29 @@ pub trait KeyValueDB: Sync + Send {
 		self.get(col, key).map(|opt| opt.is_some())
 	}
 
+	/// Check for the existence of values by key, in the order of `keys`.
+	///
+	/// Implementations may provide a faster way to rule out absent keys than looking each one up.
+	fn has_keys(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<bool>> {
+		keys.iter().map(|key| self.has_key(col, key)).collect()
+	}
+
+	/// Insert a key-value pair unless the key is already present.
+	/// Returns `true` if the value was written.
+	///
+	/// The default implementation checks for the key and then writes a transaction, so it is
+	/// not atomic: a concurrent write of the same key in between may be overwritten.
+	/// Implementations that can do better override it.
+	fn put_if_absent(&self, col: u32, key: &[u8], value: &[u8]) -> io::Result<bool> {
+		if self.has_key(col, key)? {
+			return Ok(false)
+		}
+		let mut transaction = self.transaction();
+		transaction.put(col, key, value);
+		self.write(transaction)?;
+		Ok(true)
+	}
+
 	/// Check for the existence of a value by prefix.
 	fn has_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<bool> {
 		self.get_by_prefix(col, prefix).map(|opt| opt.is_some())
@@ -170,7 +213,7 @@ pub fn end_prefix(prefix: &[u8]) -> Option<Vec<u8>> {
 
 #[cfg(test)]
 mod test {
-	use super::end_prefix;
+	use super::{end_prefix, DBTransaction, DB_OP_OVERHEAD};
 
 	#[test]
 	fn end_prefix_test() {
@@ -188,4 +231,16 @@ mod test {
 		assert_eq!(end_prefix(&[]), None);
 		assert_eq!(end_prefix(b"0"), Some(b"1".to_vec()));
 	}
+
+	#[test]
+	fn estimate_size_test() {
+		let mut transaction = DBTransaction::new();
+		assert_eq!(transaction.estimate_size(), 0);
+
+		transaction.put(0, b"key1", b"horse");
+		transaction.put_vec(1, b"key2", vec![0; 100]);
+		transaction.delete(0, b"key3");
+		transaction.delete_prefix(0, b"pre");
+		assert_eq!(transaction.estimate_size(), 4 * DB_OP_OVERHEAD + (4 + 5) + (4 + 100) + 4 + 3);
+	}
 }
@@ -92,6 +92,18 @@ impl<'a> BytesRef<'a> {
 			_ => 0,
 		}
 	}
+
+	/// Reads from this `BytesRef` starting at `offset` into `out`.
+	/// Returns number of bytes read, which is clamped to the length available from `offset`.
+	pub fn read(&self, offset: usize, out: &mut [u8]) -> usize {
+		let data: &[u8] = self;
+		if offset >= data.len() {
+			return 0
+		}
+		let max = min(data.len() - offset, out.len());
+		out[..max].copy_from_slice(&data[offset..(offset + max)]);
+		max
+	}
 }
 
 impl<'a> ops::Deref for BytesRef<'a> {
@@ -117,9 +129,66 @@ impl<'a> ops::DerefMut for BytesRef<'a> {
 /// Vector of bytes.
 pub type Bytes = Vec<u8>;
 
+/// Error returned by [`from_hex`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FromHexError {
+	/// The input, without `0x` prefix, has an odd number of characters.
+	OddLength,
+	/// The input contains a non-hex character at the given index.
+	InvalidCharacter {
+		/// The offending character.
+		character: char,
+		/// Byte index of the character in the input, including any `0x` prefix.
+		index: usize,
+	},
+}
+
+impl fmt::Display for FromHexError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			FromHexError::OddLength => write!(f, "hex string has an odd number of characters"),
+			FromHexError::InvalidCharacter { character, index } =>
+				write!(f, "invalid hex character {:?} at index {}", character, index),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromHexError {}
+
+/// Parse a hex string, with an optional `0x` prefix, into bytes.
+///
+/// This is the inverse of [`ToPretty::to_hex`].
+pub fn from_hex(s: &str) -> Result<Bytes, FromHexError> {
+	let (prefix_len, digits) = match s.strip_prefix("0x") {
+		Some(digits) => (2, digits),
+		None => (0, s),
+	};
+	if let Some((index, character)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+		return Err(FromHexError::InvalidCharacter { character, index: prefix_len + index })
+	}
+	// Only ASCII digits are left, so the byte length is the number of characters.
+	if digits.len() % 2 != 0 {
+		return Err(FromHexError::OddLength)
+	}
+
+	let nibble = |byte: u8| match byte {
+		b'0'..=b'9' => byte - b'0',
+		b'a'..=b'f' => byte - b'a' + 10,
+		_ => byte - b'A' + 10,
+	};
+
+	let bytes = digits.as_bytes();
+	let mut out = Vec::with_capacity(bytes.len() / 2);
+	for pair in bytes.chunks(2) {
+		out.push(nibble(pair[0]) << 4 | nibble(pair[1]));
+	}
+	Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
-	use super::BytesRef;
+	use super::{from_hex, BytesRef, FromHexError, ToPretty};
 	#[cfg(not(feature = "std"))]
 	use alloc::vec;
 
@@ -174,4 +243,51 @@ mod tests {
 		assert_eq!(&data3, &[0, 0, 0, 0, 0, 1, 1, 1]);
 		assert_eq!(res3, 5);
 	}
+
+	#[test]
+	fn should_read_bytes_from_bytesref() {
+		// given
+		let mut data1 = [1, 2, 3];
+		let mut data2 = vec![1, 2, 3];
+		let bytes1 = BytesRef::Fixed(&mut data1[..]);
+		let bytes2 = BytesRef::Flexible(&mut data2);
+		let mut out = [0u8; 2];
+
+		// when / then
+		assert_eq!(bytes1.read(3, &mut out), 0);
+		assert_eq!(bytes2.read(5, &mut out), 0);
+		assert_eq!(out, [0, 0]);
+
+		assert_eq!(bytes1.read(2, &mut out), 1);
+		assert_eq!(out, [3, 0]);
+
+		assert_eq!(bytes2.read(0, &mut out), 2);
+		assert_eq!(out, [1, 2]);
+
+		let mut all = [0u8; 3];
+		assert_eq!(bytes1.read(0, &mut all), 3);
+		assert_eq!(all, [1, 2, 3]);
+	}
+
+	#[test]
+	fn should_parse_hex() {
+		assert_eq!(from_hex("").unwrap(), vec![]);
+		assert_eq!(from_hex("0x").unwrap(), vec![]);
+		assert_eq!(from_hex("00ff1A").unwrap(), vec![0x00, 0xff, 0x1a]);
+		assert_eq!(from_hex("0xdeadBEEF").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+
+		let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+		assert_eq!(from_hex(&bytes.to_hex()).unwrap(), bytes);
+	}
+
+	#[test]
+	fn should_reject_invalid_hex() {
+		assert_eq!(from_hex("abc"), Err(FromHexError::OddLength));
+		assert_eq!(from_hex("0x0"), Err(FromHexError::OddLength));
+		assert_eq!(from_hex("0g"), Err(FromHexError::InvalidCharacter { character: 'g', index: 1 }));
+		assert_eq!(from_hex("0x12z4"), Err(FromHexError::InvalidCharacter { character: 'z', index: 4 }));
+		assert_eq!(from_hex("0x00é0"), Err(FromHexError::InvalidCharacter { character: 'é', index: 4 }));
+		assert_eq!(from_hex("0xé0"), Err(FromHexError::InvalidCharacter { character: 'é', index: 2 }));
+		assert_eq!(from_hex("00é"), Err(FromHexError::InvalidCharacter { character: 'é', index: 2 }));
+	}
 }
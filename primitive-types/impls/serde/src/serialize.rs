@@ -38,6 +38,10 @@ pub fn to_hex(bytes: &[u8], skip_leading_zero: bool) -> String {
 }
 
 fn to_hex_raw<'a>(v: &'a mut [u8], bytes: &[u8], skip_leading_zero: bool) -> &'a str {
+	if bytes.is_empty() {
+		return if skip_leading_zero { "0x0" } else { "0x" }
+	}
+
 	assert!(v.len() > 1 + bytes.len() * 2);
 
 	v[0] = b'0';
@@ -404,6 +408,24 @@ mod tests {
 		assert!(deserialized.0.is_empty())
 	}
 
+	#[test]
+	fn should_serialize_empty_input() {
+		let mut out = Vec::new();
+		serialize(&[], &mut serde_json::Serializer::new(&mut out)).unwrap();
+		assert_eq!(out, b"\"0x\"");
+
+		let mut out = Vec::new();
+		serialize_uint(&mut [], &[], &mut serde_json::Serializer::new(&mut out)).unwrap();
+		assert_eq!(out, b"\"0x0\"");
+
+		let mut out = Vec::new();
+		serialize_uint(&mut [], &[0, 0], &mut serde_json::Serializer::new(&mut out)).unwrap();
+		assert_eq!(out, b"\"0x0\"");
+
+		assert_eq!(to_hex_raw(&mut [], &[], false), "0x");
+		assert_eq!(to_hex_raw(&mut [], &[], true), "0x0");
+	}
+
 	#[test]
 	fn should_encode_to_and_from_hex_with_prefix() {
 		assert_eq!(to_hex(&[0, 1, 2], true), "0x102");
@@ -476,6 +498,31 @@ mod tests {
 		assert_eq!(output, vec![1, 2, 3, 0, 0]);
 	}
 
+	#[test]
+	fn should_check_len_with_and_without_prefix() {
+		type StrDeserializer<'a> = serde::de::value::StrDeserializer<'a, serde::de::value::Error>;
+
+		for input in ["0x0102030405", "0102030405"] {
+			let mut output = [0; 5];
+			let n = deserialize_check_len(StrDeserializer::new(input), ExpectedLen::Exact(&mut output)).unwrap();
+			assert_eq!(n, 5);
+			assert_eq!(output, [1, 2, 3, 4, 5]);
+
+			let mut output = [0; 4];
+			assert!(deserialize_check_len(StrDeserializer::new(input), ExpectedLen::Exact(&mut output)).is_err());
+		}
+
+		for input in ["0x010203", "010203"] {
+			let mut output = [0; 5];
+			let n = deserialize_check_len(StrDeserializer::new(input), ExpectedLen::Between(2, &mut output)).unwrap();
+			assert_eq!(n, 3);
+			assert_eq!(output, [1, 2, 3, 0, 0]);
+
+			let mut output = [0; 5];
+			assert!(deserialize_check_len(StrDeserializer::new(input), ExpectedLen::Between(3, &mut output)).is_err());
+		}
+	}
+
 	#[test]
 	fn should_deserialize_from_u8_sequence() {
 		use serde::de::value::SeqDeserializer;
@@ -1,4 +1,4 @@
-use super::U256;
+use super::{U256, U512};
 
 impl U256 {
 	/// Lossy saturating conversion from a `f64` to a `U256`. Like for floating point to
@@ -64,3 +64,60 @@ impl U256 {
 		f64::from_bits((exponent << 52) + mantissa)
 	}
 }
+
+impl U512 {
+	/// Lossy saturating conversion from a `f64` to a `U512`. Like for floating point to
+	/// primitive integer type conversions, this truncates fractional parts.
+	///
+	/// The conversion follows the same rules as converting `f64` to other
+	/// primitive integer types. Namely, the conversion of `value: f64` behaves as
+	/// follows:
+	/// - `NaN` => `0`
+	/// - `(-∞, 0]` => `0`
+	/// - `(0, u512::MAX]` => `value as u512`
+	/// - `(u512::MAX, +∞)` => `u512::MAX`
+	pub fn from_f64_lossy(value: f64) -> U512 {
+		if value >= 1.0 {
+			let bits = value.to_bits();
+			// NOTE: Don't consider the sign or check that the subtraction will
+			//   underflow since we already checked that the value is greater
+			//   than 1.0.
+			let exponent = ((bits >> 52) & 0x7ff) - 1023;
+			let mantissa = (bits & 0x0f_ffff_ffff_ffff) | 0x10_0000_0000_0000;
+			if exponent <= 52 {
+				U512::from(mantissa >> (52 - exponent))
+			} else if exponent >= 512 {
+				U512::MAX
+			} else {
+				U512::from(mantissa) << U512::from(exponent - 52)
+			}
+		} else {
+			0.into()
+		}
+	}
+
+	/// Lossy conversion of `U512` to `f64`.
+	///
+	/// Values above 2^53 are rounded to the nearest representable `f64` (ties to even),
+	/// so only the 53 most significant bits are preserved.
+	pub fn to_f64_lossy(self) -> f64 {
+		// Same algorithm as for `U256`, see the comments there.
+		let leading_zeroes = self.leading_zeros();
+		let left_aligned = self << leading_zeroes;
+		let quarter_aligned = left_aligned >> 11;
+		let mantissa = quarter_aligned.0[7];
+		let dropped_bits = quarter_aligned.0[5] |
+			quarter_aligned.0[4] |
+			quarter_aligned.0[3] |
+			quarter_aligned.0[2] |
+			quarter_aligned.0[1] |
+			quarter_aligned.0[0] |
+			(left_aligned.0[0] & 0xFFFF_FFFF);
+		let dropped_bits = (dropped_bits & 0x7FFF_FFFF_FFFF_FFFF) | (dropped_bits >> 63);
+		let dropped_bits = quarter_aligned.0[6] | dropped_bits;
+		let mantissa = mantissa + ((dropped_bits - (dropped_bits >> 63 & !mantissa)) >> 63);
+		// (511 - n) + 1022 so it simplifies to 1533 - n
+		let exponent = if self.is_zero() { 0 } else { 1533 - leading_zeroes as u64 };
+		f64::from_bits((exponent << 52) + mantissa)
+	}
+}
@@ -22,6 +22,8 @@ extern crate alloc;
 mod fp_conversion;
 #[cfg(feature = "json-schema")]
 mod json_schema;
+#[cfg(feature = "impl-serde")]
+pub mod serde_decimal;
 
 use core::convert::TryFrom;
 use fixed_hash::{construct_fixed_hash, impl_fixed_hash_conversions};
@@ -163,6 +165,38 @@ impl U256 {
 	pub fn full_mul(self, other: U256) -> U512 {
 		U512(uint_full_mul_reg!(U256, 4, self, other))
 	}
+
+	/// Converts from exactly 32 big-endian bytes.
+	#[inline]
+	pub fn from_big_endian_exact(bytes: &[u8; 32]) -> U256 {
+		U256::from_big_endian(bytes)
+	}
+
+	/// Converts from big-endian bytes, shorter input is left-padded with zeros.
+	///
+	/// Unlike `from_big_endian`, which panics, input longer than 32 bytes is rejected with
+	/// `Error::Overflow`, even if the excess leading bytes are zero.
+	#[inline]
+	pub fn from_big_endian_checked(bytes: &[u8]) -> Result<U256, Error> {
+		if bytes.len() > 32 {
+			return Err(Error::Overflow)
+		}
+		Ok(U256::from_big_endian(bytes))
+	}
+
+	/// Converts to a 128-bit integer, saturating at `U128::MAX` if the value does not fit.
+	#[inline]
+	pub fn saturating_into_u128(self) -> U128 {
+		U128::try_from(self).unwrap_or(U128::MAX)
+	}
+}
+
+impl U512 {
+	/// Converts to a 256-bit integer, saturating at `U256::MAX` if the value does not fit.
+	#[inline]
+	pub fn saturating_into_u256(self) -> U256 {
+		U256::try_from(self).unwrap_or(U256::MAX)
+	}
 }
 
 impl From<U256> for U512 {
@@ -0,0 +1,113 @@
+//! Serde helpers representing uints as decimal strings.
+//!
+//! Meant to be used with `#[serde(with = "primitive_types::serde_decimal")]` on fields
+//! that should not use the default `0x`-prefixed hex representation.
+//! Values are serialized as decimal strings, e.g. `"1000"`, while deserialization
+//! also accepts `0x`-prefixed hex strings.
+
+use crate::{U128, U256, U512};
+use core::fmt;
+use impl_serde::serde::{de, Deserializer, Serializer};
+
+/// Uint types which can be (de)serialized with the [`serde_decimal`](self) helpers.
+pub trait DecimalUint: Sized + fmt::Display {
+	/// Parse a string in the given radix. Only radixes of 10 and 16 are supported.
+	fn from_str_radix(txt: &str, radix: u32) -> Result<Self, uint::FromStrRadixErr>;
+}
+
+macro_rules! impl_decimal_uint {
+	($($name: ident),*) => {
+		$(
+			impl DecimalUint for $name {
+				fn from_str_radix(txt: &str, radix: u32) -> Result<Self, uint::FromStrRadixErr> {
+					$name::from_str_radix(txt, radix)
+				}
+			}
+		)*
+	};
+}
+
+impl_decimal_uint!(U128, U256, U512);
+
+/// Serialize a uint as a decimal string.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	T: DecimalUint,
+	S: Serializer,
+{
+	serializer.collect_str(value)
+}
+
+/// Deserialize a uint from a decimal or a `0x`-prefixed hex string.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+	T: DecimalUint,
+	D: Deserializer<'de>,
+{
+	struct Visitor<T>(core::marker::PhantomData<T>);
+
+	impl<'de, T: DecimalUint> de::Visitor<'de> for Visitor<T> {
+		type Value = T;
+
+		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+			write!(formatter, "a decimal or a 0x-prefixed hex string")
+		}
+
+		fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+			let (digits, radix) = match v.strip_prefix("0x") {
+				Some(hex) => (hex, 16),
+				None => (v, 10),
+			};
+			if digits.is_empty() {
+				return Err(E::invalid_value(de::Unexpected::Str(v), &self))
+			}
+			T::from_str_radix(digits, radix).map_err(E::custom)
+		}
+	}
+
+	deserializer.deserialize_str(Visitor(core::marker::PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[cfg(not(feature = "std"))]
+	use alloc::{format, string::String, vec::Vec};
+
+	fn to_json<T: DecimalUint>(value: &T) -> String {
+		let mut out = Vec::new();
+		serialize(value, &mut serde_json::Serializer::new(&mut out)).unwrap();
+		String::from_utf8(out).unwrap()
+	}
+
+	fn from_json<T: DecimalUint>(json: &str) -> Result<T, serde_json::Error> {
+		deserialize(&mut serde_json::Deserializer::from_str(json))
+	}
+
+	#[test]
+	fn roundtrip() {
+		let values = [U256::zero(), U256::from(1_000u64), U256::exp10(40), U256::MAX];
+		for value in values {
+			let json = to_json(&value);
+			assert_eq!(json, format!("\"{}\"", value));
+			assert_eq!(from_json::<U256>(&json).unwrap(), value);
+		}
+		assert_eq!(to_json(&U128::from(42u64)), "\"42\"");
+		assert_eq!(from_json::<U512>(&to_json(&U512::MAX)).unwrap(), U512::MAX);
+	}
+
+	#[test]
+	fn accepts_hex() {
+		assert_eq!(from_json::<U256>("\"0x3e8\"").unwrap(), U256::from(1_000u64));
+		assert_eq!(from_json::<U256>("\"0x0\"").unwrap(), U256::zero());
+	}
+
+	#[test]
+	fn rejects_invalid_input() {
+		assert!(from_json::<U256>("1000").is_err());
+		assert!(from_json::<U256>("\"\"").is_err());
+		assert!(from_json::<U256>("\"0x\"").is_err());
+		assert!(from_json::<U256>("\"12a\"").is_err());
+		assert!(from_json::<U128>("\"340282366920938463463374607431768211456\"").is_err());
+	}
+}
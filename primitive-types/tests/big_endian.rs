@@ -0,0 +1,33 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use primitive_types::{Error, U256};
+
+#[test]
+fn from_big_endian_exact() {
+	let mut bytes = [0u8; 32];
+	bytes[0] = 0x80;
+	bytes[31] = 0x01;
+	let value = U256::from_big_endian_exact(&bytes);
+	assert_eq!(value, (U256::one() << 255) + 1);
+	assert_eq!(value.to_big_endian(), bytes);
+	assert_eq!(U256::from_big_endian_exact(&[0xff; 32]), U256::MAX);
+}
+
+#[test]
+fn from_big_endian_checked_pads_short_input() {
+	assert_eq!(U256::from_big_endian_checked(&[]), Ok(U256::zero()));
+	assert_eq!(U256::from_big_endian_checked(&[0x01, 0x00]), Ok(U256::from(256)));
+	assert_eq!(U256::from_big_endian_checked(&[0xff; 32]), Ok(U256::MAX));
+}
+
+#[test]
+fn from_big_endian_checked_rejects_long_input() {
+	assert_eq!(U256::from_big_endian_checked(&[0x01; 33]), Err(Error::Overflow));
+	assert_eq!(U256::from_big_endian_checked(&[0x00; 33]), Err(Error::Overflow));
+}
@@ -0,0 +1,40 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use primitive_types::{H128, H160, H256, H384, H512, H768};
+
+macro_rules! test_from_low {
+	($name:ident, $hash:ident) => {
+		#[test]
+		fn $name() {
+			let len = $hash::len_bytes();
+			let val64 = 0x0123_4567_89AB_CDEFu64;
+			assert_eq!($hash::from_low_u64_be(val64).as_bytes()[len - 8..], val64.to_be_bytes());
+			assert_eq!($hash::from_low_u64_le(val64).as_bytes()[len - 8..], val64.to_le_bytes());
+			assert!($hash::from_low_u64_be(val64).as_bytes()[..len - 8]
+				.iter()
+				.all(|&byte| byte == 0));
+			assert_eq!($hash::from_low_u64_be(val64).to_low_u64_be(), val64);
+
+			let val128 = 0x0123_4567_89AB_CDEF_0011_2233_4455_6677u128;
+			assert_eq!($hash::from_low_u128_be(val128).as_bytes()[len - 16..], val128.to_be_bytes());
+			assert_eq!($hash::from_low_u128_le(val128).as_bytes()[len - 16..], val128.to_le_bytes());
+			assert!($hash::from_low_u128_le(val128).as_bytes()[..len - 16]
+				.iter()
+				.all(|&byte| byte == 0));
+			assert_eq!($hash::from_low_u128_be(val64 as u128), $hash::from_low_u64_be(val64));
+		}
+	};
+}
+
+test_from_low!(h128_from_low, H128);
+test_from_low!(h160_from_low, H160);
+test_from_low!(h256_from_low, H256);
+test_from_low!(h384_from_low, H384);
+test_from_low!(h512_from_low, H512);
+test_from_low!(h768_from_low, H768);
@@ -6,9 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Testing to and from f64 lossy for U256 primitive type.
+//! Testing to and from f64 lossy for U256 and U512 primitive types.
 
-use primitive_types::U256;
+use primitive_types::{U256, U512};
 
 #[test]
 #[allow(clippy::float_cmp)]
@@ -74,3 +74,63 @@ fn convert_f64_to_u256_non_normal() {
 fn f64_to_u256_truncation() {
 	assert_eq!(U256::from_f64_lossy(10.5), 10.into());
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn convert_u512_to_f64() {
+	assert_eq!(U512::from(0).to_f64_lossy(), 0.0);
+	assert_eq!(U512::from(42).to_f64_lossy(), 42.0);
+	assert_eq!(U512::from(1_000_000_000_000_000_000u128).to_f64_lossy(), 1_000_000_000_000_000_000.0);
+	assert_eq!(U512::from(U256::MAX).to_f64_lossy(), U256::MAX.to_f64_lossy());
+	assert_eq!((U512::from(1) << 511).to_f64_lossy(), 2f64.powi(511));
+	assert_eq!(U512::MAX.to_f64_lossy(), 2f64.powi(512));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn convert_u512_to_f64_precision_loss() {
+	// 2^53 + 1 is not representable and rounds to even.
+	let two_53 = U512::from(1u64 << 53);
+	assert_eq!((two_53 + 1).to_f64_lossy(), 9_007_199_254_740_992.0);
+	assert_eq!((two_53 + 3).to_f64_lossy(), 9_007_199_254_740_996.0);
+	// A dropped bit far below the mantissa still breaks the tie upwards.
+	let tie = (U512::from(1) << 500) + (U512::from(1) << 447);
+	assert_eq!(tie.to_f64_lossy(), 2f64.powi(500));
+	assert_eq!((tie + 1).to_f64_lossy(), 2f64.powi(500) + 2f64.powi(448));
+}
+
+#[test]
+fn convert_f64_to_u512() {
+	assert_eq!(U512::from_f64_lossy(0.0), 0.into());
+	assert_eq!(U512::from_f64_lossy(13.37), 13.into());
+	assert_eq!(U512::from_f64_lossy(999.999), 999.into());
+	assert_eq!(U512::from_f64_lossy(1_000_000_000_000_000_000.0), 1_000_000_000_000_000_000u128.into());
+	assert_eq!(U512::from_f64_lossy(2f64.powi(300)), U512::from(1) << 300);
+	assert_eq!(U512::from_f64_lossy(2f64.powi(511)), U512::from(1) << 511);
+}
+
+#[test]
+fn convert_f64_to_u512_overflow_and_non_normal() {
+	assert_eq!(U512::from_f64_lossy(2f64.powi(512)), U512::MAX);
+	assert_eq!(U512::from_f64_lossy(f64::MAX), U512::MAX);
+	assert_eq!(U512::from_f64_lossy(f64::INFINITY), U512::MAX);
+	assert_eq!(U512::from_f64_lossy(f64::NAN), 0.into());
+	assert_eq!(U512::from_f64_lossy(-1.0), 0.into());
+	assert_eq!(U512::from_f64_lossy(f64::NEG_INFINITY), 0.into());
+	assert_eq!(U512::from_f64_lossy(f64::EPSILON), 0.into());
+}
+
+#[test]
+fn u512_f64_conversion_is_monotonic() {
+	let mut previous = 0.0;
+	for shift in 0..512 {
+		let power = U512::from(1) << shift;
+		assert_eq!(U512::from_f64_lossy(power.to_f64_lossy()), power);
+
+		for value in [power, power + (power >> 1), power + (power >> 1) + 1] {
+			let float = value.to_f64_lossy();
+			assert!(float >= previous, "not monotonic at {}", value);
+			previous = float;
+		}
+	}
+}
@@ -30,18 +30,58 @@ pub fn impl_decodable(ast: &syn::DeriveInput) -> TokenStream {
 		panic!("#[derive(RlpDecodable)] is only defined for structs.");
 	};
 
+	let fields: Vec<_> = body.fields.iter().filter(|field| !crate::is_skipped(field)).collect();
+	let flattened_fields = fields.iter().filter(|field| crate::is_flattened(field)).count();
+	assert!(flattened_fields <= 1, "only 1 #[rlp(flatten)] attribute is allowed in a struct");
+	assert!(
+		flattened_fields == 0 || !fields.iter().any(|field| crate::has_rlp_attribute(field, "default")),
+		"#[rlp(flatten)] can't be combined with #[rlp(default)]"
+	);
+
 	let mut default_attribute_encountered = false;
+	let mut skipped_fields = 0;
+	let mut flattened_field_encountered = false;
 	let stmts: Vec<_> = body
 		.fields
 		.iter()
 		.enumerate()
-		.map(|(i, field)| decodable_field(i, field, decodable_parse_quotes(), &mut default_attribute_encountered))
+		.map(|(i, field)| {
+			if crate::is_skipped(field) {
+				skipped_fields += 1;
+				skipped_field(i, field)
+			} else if crate::is_flattened(field) {
+				flattened_field_encountered = true;
+				flattened_field(i, i - skipped_fields, field)
+			} else {
+				decodable_field(
+					i,
+					i - skipped_fields,
+					flattened_field_encountered,
+					field,
+					decodable_parse_quotes(),
+					&mut default_attribute_encountered,
+				)
+			}
+		})
 		.collect();
 	let name = &ast.ident;
 
+	let flattened_len = if flattened_field_encountered {
+		let regular_fields = fields.len() - 1;
+		quote! {
+			let flattened_len = rlp
+				.item_count()?
+				.checked_sub(#regular_fields)
+				.ok_or(rlp::DecoderError::RlpIncorrectListLen)?;
+		}
+	} else {
+		quote! {}
+	};
+
 	let impl_block = quote! {
 		impl rlp::Decodable for #name {
 			fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+				#flattened_len
 				let result = #name {
 					#(#stmts)*
 				};
@@ -71,7 +111,7 @@ pub fn impl_decodable_wrapper(ast: &syn::DeriveInput) -> TokenStream {
 		if fields.len() == 1 {
 			let field = fields.first().expect("fields.len() == 1; qed");
 			let mut default_attribute_encountered = false;
-			decodable_field(0, field, decodable_wrapper_parse_quotes(), &mut default_attribute_encountered)
+			decodable_field(0, 0, false, field, decodable_wrapper_parse_quotes(), &mut default_attribute_encountered)
 		} else {
 			panic!("#[derive(RlpEncodableWrapper)] is only defined for structs with one field.")
 		}
@@ -99,23 +139,43 @@ pub fn impl_decodable_wrapper(ast: &syn::DeriveInput) -> TokenStream {
 	}
 }
 
+fn skipped_field(index: usize, field: &syn::Field) -> TokenStream {
+	let id = crate::field_id(index, field);
+	quote! { #id: Default::default(), }
+}
+
+fn flattened_field(index: usize, rlp_index: usize, field: &syn::Field) -> TokenStream {
+	let id = crate::field_id(index, field);
+	quote! {
+		#id: {
+			let mut flattened = rlp::RlpStream::new_list(flattened_len);
+			for index in #rlp_index..#rlp_index + flattened_len {
+				flattened.append_raw(rlp.at(index)?.as_raw(), 1);
+			}
+			rlp::decode(&flattened.out())?
+		},
+	}
+}
+
 fn decodable_field(
-	mut index: usize,
+	index: usize,
+	mut rlp_index: usize,
+	after_flattened_field: bool,
 	field: &syn::Field,
 	quotes: ParseQuotes,
 	default_attribute_encountered: &mut bool,
 ) -> TokenStream {
-	let id = if let Some(ident) = &field.ident {
-		quote! { #ident }
-	} else {
-		let index = syn::Index::from(index);
-		quote! { #index }
-	};
+	let id = crate::field_id(index, field);
 
 	if *default_attribute_encountered {
-		index -= 1;
+		rlp_index -= 1;
 	}
-	let index = quote! { #index };
+	// The flattened field takes up `flattened_len` items instead of one.
+	let index = if after_flattened_field {
+		quote! { #rlp_index + flattened_len - 1 }
+	} else {
+		quote! { #rlp_index }
+	};
 
 	let single = quotes.single;
 	let list = quotes.list;
@@ -127,7 +187,7 @@ fn decodable_field(
 		}
 		match attr.parse_args() {
 			Ok(proc_macro2::TokenTree::Ident(ident)) if ident == "default" => {},
-			_ => panic!("only #[rlp(default)] attribute is supported"),
+			_ => panic!("only #[rlp(default)], #[rlp(skip)] and #[rlp(flatten)] attributes are supported"),
 		}
 		*default_attribute_encountered = true;
 		true
@@ -16,20 +16,44 @@ pub fn impl_encodable(ast: &syn::DeriveInput) -> TokenStream {
 		panic!("#[derive(RlpEncodable)] is only defined for structs.");
 	};
 
-	let stmts: Vec<_> = body
+	let fields: Vec<_> = body
 		.fields
 		.iter()
 		.enumerate()
-		.map(|(i, field)| encodable_field(i, field))
+		.filter(|(_, field)| !crate::is_skipped(field))
+		.collect();
+	assert!(
+		fields.iter().filter(|(_, field)| crate::is_flattened(field)).count() <= 1,
+		"only 1 #[rlp(flatten)] attribute is allowed in a struct"
+	);
+
+	let stmts_len = fields.len();
+	let mut list_len = quote! { #stmts_len };
+	let mut flattened = quote! {};
+	let stmts: Vec<_> = fields
+		.iter()
+		.map(|(i, field)| {
+			if crate::is_flattened(field) {
+				let id = crate::field_id(*i, field);
+				flattened = quote! {
+					let flattened = rlp::encode(&self.#id);
+					let flattened = rlp::Rlp::new(&flattened);
+					let flattened_len = flattened.item_count().expect("#[rlp(flatten)] field must encode as a list");
+				};
+				list_len = quote! { #stmts_len - 1 + flattened_len };
+				quote! { stream.append_raw(flattened.data().expect("encoded list is valid; qed"), flattened_len); }
+			} else {
+				encodable_field(*i, field)
+			}
+		})
 		.collect();
 	let name = &ast.ident;
 
-	let stmts_len = stmts.len();
-	let stmts_len = quote! { #stmts_len };
 	let impl_block = quote! {
 		impl rlp::Encodable for #name {
 			fn rlp_append(&self, stream: &mut rlp::RlpStream) {
-				stream.begin_list(#stmts_len);
+				#flattened
+				stream.begin_list(#list_len);
 				#(#stmts)*
 			}
 		}
@@ -79,12 +103,7 @@ pub fn impl_encodable_wrapper(ast: &syn::DeriveInput) -> TokenStream {
 }
 
 fn encodable_field(index: usize, field: &syn::Field) -> TokenStream {
-	let ident = if let Some(ident) = &field.ident {
-		quote! { #ident }
-	} else {
-		let index = syn::Index::from(index);
-		quote! { #index }
-	};
+	let ident = crate::field_id(index, field);
 
 	let id = quote! { self.#ident };
 
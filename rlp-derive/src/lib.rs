@@ -15,6 +15,15 @@
 //! with the caveat that we use the `Default` value if
 //! the field deserialization fails, as we don't serialize field
 //! names and there is no way to tell if it is present or not.
+//!
+//! Fields marked with `#[rlp(skip)]` are left out of the encoded list
+//! and set to their `Default` value on decoding.
+//!
+//! Up to 1 field per struct can be marked with `#[rlp(flatten)]`, in which case the
+//! items of its own list encoding are embedded in the enclosing list instead of a
+//! nested list. The field type must itself be `RlpEncodable`/`RlpDecodable`.
+//! Since the number of flattened items on decoding is derived from the length of the
+//! enclosing list, `#[rlp(flatten)]` can't be combined with `#[rlp(default)]`.
 
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
@@ -54,3 +63,32 @@ pub fn decodable_wrapper(input: TokenStream) -> TokenStream {
 	let gen = impl_decodable_wrapper(&ast);
 	gen.into()
 }
+
+/// The name of the field, or its index for tuple structs.
+fn field_id(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+	if let Some(ident) = &field.ident {
+		quote::quote! { #ident }
+	} else {
+		let index = syn::Index::from(index);
+		quote::quote! { #index }
+	}
+}
+
+/// Whether the field is marked with `#[rlp(<name>)]`.
+fn has_rlp_attribute(field: &syn::Field, name: &str) -> bool {
+	field
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("rlp"))
+		.any(|attr| matches!(attr.parse_args(), Ok(proc_macro2::TokenTree::Ident(ident)) if ident == name))
+}
+
+/// Whether the field is marked with `#[rlp(skip)]`.
+fn is_skipped(field: &syn::Field) -> bool {
+	has_rlp_attribute(field, "skip")
+}
+
+/// Whether the field is marked with `#[rlp(flatten)]`.
+fn is_flattened(field: &syn::Field) -> bool {
+	has_rlp_attribute(field, "flatten")
+}
@@ -69,3 +69,89 @@ fn test_encode_item_default() {
 	let out = encode(&item_some);
 	assert_eq!(decode(&out), Ok(item_some));
 }
+
+#[test]
+fn test_encode_item_skip() {
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct ItemSkip {
+		#[rlp(skip)]
+		cached_len: usize,
+		a: String,
+		#[rlp(skip)]
+		cached_hash: Option<[u8; 4]>,
+		b: u64,
+	}
+
+	let item = ItemSkip { cached_len: 3, a: "cat".into(), cached_hash: Some([1, 2, 3, 4]), b: 7 };
+
+	let expected = vec![0xc5, 0x83, b'c', b'a', b't', 0x07];
+	let out = encode(&item);
+	assert_eq!(out, expected);
+
+	let decoded: ItemSkip = decode(&expected).expect("decode failure");
+	assert_eq!(decoded, ItemSkip { cached_len: 0, a: "cat".into(), cached_hash: None, b: 7 });
+	assert_eq!(encode(&decoded), expected);
+}
+
+#[test]
+fn test_encode_tuple_item_skip() {
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct TupleSkip(#[rlp(skip)] u8, String, #[rlp(skip)] bool, Vec<u8>);
+
+	let item = TupleSkip(9, "dog".into(), true, vec![1, 2]);
+	let out = encode(&item);
+	assert_eq!(out, vec![0xc7, 0x83, b'd', b'o', b'g', 0xc2, 0x01, 0x02]);
+	assert_eq!(decode(&out), Ok(TupleSkip(0, "dog".into(), false, vec![1, 2])));
+}
+
+#[test]
+fn test_encode_item_flatten() {
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct Header {
+		number: u64,
+		hash: Vec<u8>,
+	}
+
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct Block {
+		version: u8,
+		#[rlp(flatten)]
+		header: Header,
+		#[rlp(skip)]
+		cached_size: usize,
+		extra: String,
+	}
+
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct FlatBlock {
+		version: u8,
+		number: u64,
+		hash: Vec<u8>,
+		extra: String,
+	}
+
+	let block =
+		Block { version: 1, header: Header { number: 1024, hash: vec![7, 8] }, cached_size: 42, extra: "cat".into() };
+	let flat = FlatBlock { version: 1, number: 1024, hash: vec![7, 8], extra: "cat".into() };
+
+	let out = encode(&block);
+	assert_eq!(out, encode(&flat));
+	assert_eq!(decode::<FlatBlock>(&out), Ok(flat));
+	assert_eq!(decode(&out), Ok(Block { cached_size: 0, ..block }));
+
+	assert_eq!(decode::<Block>(&[0xc1, 0x01]), Err(rlp::DecoderError::RlpIncorrectListLen));
+}
+
+#[test]
+fn test_encode_tuple_item_flatten() {
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct Pair(u8, u8);
+
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct Wrapper(#[rlp(flatten)] Pair, u8);
+
+	let item = Wrapper(Pair(1, 2), 3);
+	let out = encode(&item);
+	assert_eq!(out, vec![0xc3, 0x01, 0x02, 0x03]);
+	assert_eq!(decode(&out), Ok(item));
+}
@@ -44,16 +44,17 @@ mod stream;
 mod traits;
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use bytes::BytesMut;
-use core::borrow::Borrow;
+use core::{borrow::Borrow, fmt::Write};
+use rustc_hex::ToHex;
 
 #[cfg(feature = "derive")]
 pub use rlp_derive::{RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
 
 pub use self::{
 	error::DecoderError,
-	rlpin::{PayloadInfo, Prototype, Rlp, RlpIterator},
+	rlpin::{PayloadInfo, Prototype, Rlp, RlpIterator, RlpOffsetIterator},
 	stream::RlpStream,
 	traits::{Decodable, Encodable},
 };
@@ -86,6 +87,110 @@ where
 	rlp.as_list().expect("trusted rlp should be valid")
 }
 
+/// Lazily decode a list, one item at a time.
+///
+/// Unlike `decode_list`, items are decoded on demand and never collected, which keeps memory
+/// usage flat for huge lists. The outer error is returned if `bytes` is not a list, errors
+/// of the individual items are yielded by the iterator. An item whose length cannot be
+/// determined ends the iteration, since the following items cannot be located.
+///
+/// ```
+/// let data = vec![0xc4, 0x01, 0x02, 0x81, 0xff];
+/// let items: Result<Vec<u16>, _> = rlp::decode_list_iter(&data).expect("data is a list").collect();
+/// assert_eq!(items, Ok(vec![1, 2, 255]));
+/// ```
+pub fn decode_list_iter<T>(bytes: &[u8]) -> Result<impl Iterator<Item = Result<T, DecoderError>> + '_, DecoderError>
+where
+	T: Decodable,
+{
+	let rlp = Rlp::new(bytes);
+	if !rlp.is_list() {
+		return Err(DecoderError::RlpExpectedToBeList)
+	}
+	let mut items = rlp.data()?;
+	Ok(core::iter::from_fn(move || {
+		if items.is_empty() {
+			return None
+		}
+		let item = match Rlp::new(items).payload_info() {
+			Ok(info) => {
+				let (item, rest) = items.split_at(info.total());
+				items = rest;
+				T::decode(&Rlp::new(item))
+			},
+			Err(err) => {
+				items = &[];
+				Err(err)
+			},
+		};
+		Some(item)
+	}))
+}
+
+/// Dump rlp as an indented tree, for debugging.
+///
+/// Every item is printed on its own line: lists with their number of items and payload length,
+/// values with their payload length and hex. Malformed input does not panic, the error is
+/// printed in place of the item that could not be decoded.
+///
+/// ```
+/// let data = vec![0xc5, 0x83, b'c', b'a', b't', 0xc0];
+/// assert_eq!(
+///     rlp::pretty_print(&data),
+///     "list (2 items, 5 bytes)\n  data (3 bytes): 0x636174\n  list (0 items, 0 bytes)\n",
+/// );
+/// ```
+pub fn pretty_print(bytes: &[u8]) -> String {
+	let mut out = String::new();
+	let rlp = Rlp::new(bytes);
+	pretty_print_item(&mut out, &rlp, 0);
+	match rlp.payload_info() {
+		Ok(info) if info.total() < bytes.len() => pretty_print_error(&mut out, DecoderError::RlpIsTooBig, 0),
+		_ => {},
+	}
+	out
+}
+
+fn pretty_print_item(out: &mut String, rlp: &Rlp, depth: usize) {
+	let (prototype, payload) = match (rlp.prototype(), rlp.data()) {
+		(Ok(prototype), Ok(payload)) => (prototype, payload),
+		(Err(err), _) | (_, Err(err)) => return pretty_print_error(out, err, depth),
+	};
+	match prototype {
+		Prototype::Null => pretty_print_error(out, DecoderError::RlpIsTooShort, depth),
+		Prototype::Data(len) => {
+			let _ = writeln!(
+				out,
+				"{:indent$}data ({} bytes): 0x{}",
+				"",
+				len,
+				payload.to_hex::<String>(),
+				indent = 2 * depth
+			);
+		},
+		Prototype::List(count) => {
+			let _ = writeln!(out, "{:indent$}list ({} items, {} bytes)", "", count, payload.len(), indent = 2 * depth);
+			let mut consumed = 0;
+			for item in rlp.iter() {
+				pretty_print_item(out, &item, depth + 1);
+				consumed += item.as_raw().len();
+			}
+			// The iterator stops at the first malformed item, report why.
+			if consumed < payload.len() {
+				let err = Rlp::new(&payload[consumed..])
+					.payload_info()
+					.err()
+					.unwrap_or(DecoderError::RlpIsTooShort);
+				pretty_print_error(out, err, depth + 1);
+			}
+		},
+	}
+}
+
+fn pretty_print_error(out: &mut String, err: DecoderError, depth: usize) {
+	let _ = writeln!(out, "{:indent$}error: {:?}", "", err, indent = 2 * depth);
+}
+
 /// Shortcut function to encode structure into rlp.
 ///
 /// ```
@@ -8,7 +8,7 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec::Vec};
-use core::{cell::Cell, fmt};
+use core::{cell::Cell, fmt, ops::Range};
 
 use rustc_hex::ToHex;
 
@@ -151,6 +151,7 @@ impl<'a> Rlp<'a> {
 		BasicDecoder::payload_info(self.bytes)
 	}
 
+	/// Returns the payload of this item without its header, for lists as well as values.
 	pub fn data<'view>(&'view self) -> Result<&'a [u8], DecoderError>
 	where
 		'a: 'view,
@@ -159,6 +160,18 @@ impl<'a> Rlp<'a> {
 		Ok(&self.bytes[pi.header_len..(pi.header_len + pi.value_len)])
 	}
 
+	/// Returns the payload of this value item, borrowed from the underlying data.
+	/// Fails with `RlpExpectedToBeData` if this item is a list.
+	pub fn as_bytes<'view>(&'view self) -> Result<&'a [u8], DecoderError>
+	where
+		'a: 'view,
+	{
+		if self.is_list() {
+			return Err(DecoderError::RlpExpectedToBeData)
+		}
+		self.data()
+	}
+
 	pub fn item_count(&self) -> Result<usize, DecoderError> {
 		if self.is_list() {
 			match self.count_cache.get() {
@@ -269,6 +282,15 @@ impl<'a> Rlp<'a> {
 		self.into_iter()
 	}
 
+	/// Returns an iterator over the list items along with the byte range each item
+	/// spans in the raw data slice, header included.
+	pub fn iter_with_offset<'view>(&'view self) -> RlpOffsetIterator<'a, 'view>
+	where
+		'a: 'view,
+	{
+		RlpOffsetIterator { rlp: self, index: 0 }
+	}
+
 	pub fn as_val<T>(&self) -> Result<T, DecoderError>
 	where
 		T: Decodable,
@@ -297,6 +319,15 @@ impl<'a> Rlp<'a> {
 		self.at(index)?.as_list()
 	}
 
+	/// Returns the payload of the item at `index` without copying it.
+	/// Fails with `RlpExpectedToBeData` if that item is a list.
+	pub fn data_at<'view>(&'view self, index: usize) -> Result<&'a [u8], DecoderError>
+	where
+		'a: 'view,
+	{
+		self.at(index)?.as_bytes()
+	}
+
 	pub fn decoder(&self) -> BasicDecoder {
 		BasicDecoder::new(self.bytes)
 	}
@@ -371,6 +402,35 @@ impl<'a, 'view> ExactSizeIterator for RlpIterator<'a, 'view> {
 	}
 }
 
+/// Iterator over rlp-slice list elements and their byte ranges.
+pub struct RlpOffsetIterator<'a, 'view>
+where
+	'a: 'view,
+{
+	rlp: &'view Rlp<'a>,
+	index: usize,
+}
+
+impl<'a, 'view> Iterator for RlpOffsetIterator<'a, 'view> {
+	type Item = (Rlp<'a>, Range<usize>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let index = self.index;
+		let result = self.rlp.at_with_offset(index).ok().map(|(rlp, offset)| {
+			let end = offset + rlp.as_raw().len();
+			(rlp, offset..end)
+		});
+		self.index += 1;
+		result
+	}
+}
+
+impl<'a, 'view> ExactSizeIterator for RlpOffsetIterator<'a, 'view> {
+	fn len(&self) -> usize {
+		self.rlp.item_count().unwrap_or(0).saturating_sub(self.index)
+	}
+}
+
 pub struct BasicDecoder<'a> {
 	rlp: &'a [u8],
 }
@@ -11,7 +11,7 @@ use alloc::vec::Vec;
 use bytes::{BufMut, BytesMut};
 use core::borrow::Borrow;
 
-use crate::traits::Encodable;
+use crate::{error::DecoderError, rlpin::Rlp, traits::Encodable};
 
 #[derive(Debug, Copy, Clone)]
 struct ListInfo {
@@ -154,6 +154,29 @@ impl RlpStream {
 		self
 	}
 
+	/// Appends each of the values to the end of stream as a separate item, chainable.
+	///
+	/// Unlike `append_list`, the values are not wrapped in a sub-list: this is the same as
+	/// calling `append` for each of them, so every value counts towards the current list.
+	///
+	/// ```
+	/// use rlp::RlpStream;
+	/// let mut stream = RlpStream::new_list(3);
+	/// stream.append(&"cat").append_flat(vec!["dog", "cow"]);
+	/// let out = stream.out();
+	/// assert_eq!(out, vec![0xcc, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g', 0x83, b'c', b'o', b'w']);
+	/// ```
+	pub fn append_flat<I, E>(&mut self, values: I) -> &mut Self
+	where
+		I: IntoIterator<Item = E>,
+		E: Encodable,
+	{
+		for value in values {
+			self.append(&value);
+		}
+		self
+	}
+
 	/// Appends value to the end of stream, but do not count it as an appended item.
 	/// It's useful for wrapper types
 	pub fn append_internal<E>(&mut self, value: &E) -> &mut Self
@@ -221,6 +244,24 @@ impl RlpStream {
 		true
 	}
 
+	/// Appends raw (pre-serialised) RLP data, chainable. Unlike `append_raw`, checks
+	/// that `bytes` are exactly `item_count` well-formed top-level RLP items first.
+	///
+	/// Nothing is appended if the check fails.
+	pub fn append_raw_validated(&mut self, bytes: &[u8], item_count: usize) -> Result<&mut Self, DecoderError> {
+		let mut remaining = bytes;
+		let mut count = 0;
+		while !remaining.is_empty() {
+			let item = Rlp::new(remaining).payload_info()?;
+			remaining = &remaining[item.total()..];
+			count += 1;
+		}
+		if count != item_count {
+			return Err(DecoderError::RlpIncorrectListLen)
+		}
+		Ok(self.append_raw(bytes, item_count))
+	}
+
 	/// Calculate total RLP size for appended payload.
 	pub fn estimate_size(&self, add: usize) -> usize {
 		let total_size = self.total_written() + add;
@@ -57,6 +57,34 @@ fn rlp_at() {
 	}
 }
 
+#[test]
+fn rlp_data_at() {
+	let data = vec![0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g'];
+	let rlp = Rlp::new(&data);
+	let dog = rlp.data_at(1).unwrap();
+	assert_eq!(dog, b"dog");
+	assert_eq!(dog.as_ptr(), data[6..].as_ptr());
+	assert_eq!(rlp.data_at(2), Err(DecoderError::RlpIsTooShort));
+
+	let nested = vec![0xc3, 0xc2, 0x01, 0x02];
+	assert_eq!(Rlp::new(&nested).data_at(0), Err(DecoderError::RlpExpectedToBeData));
+}
+
+#[test]
+fn rlp_as_bytes() {
+	let data = vec![0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g'];
+	let rlp = Rlp::new(&data);
+	let cat = rlp.at(0).unwrap();
+	let borrowed = cat.as_bytes().unwrap();
+	assert_eq!(borrowed, &cat.as_val::<Vec<u8>>().unwrap()[..]);
+	assert_eq!(borrowed.as_ptr(), data[2..].as_ptr());
+	assert_eq!(rlp.as_bytes(), Err(DecoderError::RlpExpectedToBeData));
+
+	let single_byte = [0x05];
+	assert_eq!(Rlp::new(&single_byte).as_bytes().unwrap(), &Rlp::new(&single_byte).as_val::<Vec<u8>>().unwrap()[..]);
+	assert_eq!(Rlp::new(&[0x80]).as_bytes().unwrap(), &[] as &[u8]);
+}
+
 #[test]
 fn rlp_at_with_offset() {
 	let data = vec![0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g'];
@@ -86,6 +114,31 @@ fn rlp_at_with_offset() {
 	}
 }
 
+#[test]
+fn rlp_iter_with_offset() {
+	// [[1, 2], "cat", []]
+	let data = vec![0xc8, 0xc2, 0x01, 0x02, 0x83, b'c', b'a', b't', 0xc0];
+	let rlp = Rlp::new(&data);
+	let items: Vec<_> = rlp.iter_with_offset().collect();
+	assert_eq!(items.len(), 3);
+	assert_eq!(rlp.iter_with_offset().len(), 3);
+
+	let ranges: Vec<_> = items.iter().map(|(_, range)| range.clone()).collect();
+	assert_eq!(ranges, vec![1..4, 4..8, 8..9]);
+	for (item, range) in &items {
+		assert_eq!(&data[range.clone()], item.as_raw());
+	}
+	assert_eq!(items[0].0.as_list::<u8>().unwrap(), vec![1, 2]);
+	assert_eq!(items[1].0.as_val::<String>().unwrap(), "cat");
+	assert!(items[2].0.is_empty());
+
+	// offsets of nested items are relative to their own list
+	let nested: Vec<_> = items[0].0.iter_with_offset().map(|(_, range)| range).collect();
+	assert_eq!(nested, vec![1..2, 2..3]);
+
+	assert_eq!(Rlp::new(&[0x83, b'd', b'o', b'g']).iter_with_offset().count(), 0);
+}
+
 #[test]
 fn rlp_at_err() {
 	let data = vec![0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o'];
@@ -475,6 +528,28 @@ fn decode_untrusted_u128() {
 	run_decode_tests(tests);
 }
 
+#[test]
+fn u128_roundtrip() {
+	let mut values = vec![0, 1, 0x7f, 0x80, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX];
+	let mut value = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c834_u128;
+	for _ in 0..32 {
+		value = value.wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645).wrapping_add(1);
+		values.push(value >> (value % 128));
+	}
+
+	for value in values {
+		let encoded = rlp::encode(&value);
+		assert_eq!(rlp::decode::<u128>(&encoded), Ok(value));
+		if value > 0x7f {
+			assert_ne!(encoded[1], 0, "no leading zero bytes");
+		}
+	}
+	assert_eq!(rlp::encode(&u128::MAX).to_vec(), hex!("90ffffffffffffffffffffffffffffffff"));
+
+	assert_eq!(rlp::decode::<u128>(&hex!("820001")), Err(DecoderError::RlpInvalidIndirection));
+	assert_eq!(rlp::decode::<u128>(&hex!("9101ffffffffffffffffffffffffffffffff")), Err(DecoderError::RlpIsTooBig));
+}
+
 #[test]
 fn decode_untrusted_u256() {
 	let tests = vec![
@@ -615,6 +690,57 @@ fn test_rlp_stream_size_limit() {
 	}
 }
 
+#[test]
+fn test_rlp_stream_append_raw_validated() {
+	let cat = rlp::encode(&"cat");
+	let dog = rlp::encode(&"dog");
+	let both = [&cat[..], &dog[..]].concat();
+
+	let mut stream = RlpStream::new_list(3);
+	stream.append_raw_validated(&both, 2).unwrap().append(&"cow");
+	assert!(stream.is_finished());
+	assert_eq!(Rlp::new(&stream.out()).as_list::<String>().unwrap(), vec!["cat", "dog", "cow"]);
+
+	let mut stream = RlpStream::new_list(1);
+	assert_eq!(stream.append_raw_validated(&both, 1).err(), Some(DecoderError::RlpIncorrectListLen));
+	assert_eq!(stream.append_raw_validated(&both, 3).err(), Some(DecoderError::RlpIncorrectListLen));
+	// declared length runs past the end of the data
+	assert_eq!(stream.append_raw_validated(&[0x83, b'c', b'a'], 1).err(), Some(DecoderError::RlpIsTooShort));
+	assert_eq!(stream.append_raw_validated(&[0xc3, 0x01], 1).err(), Some(DecoderError::RlpIsTooShort));
+	// non-canonical long length prefix
+	assert_eq!(stream.append_raw_validated(&[0xb8, 0x01, 0x01], 1).err(), Some(DecoderError::RlpInvalidIndirection));
+	// nothing was appended by the failed attempts
+	assert!(!stream.is_finished());
+	stream.append_raw_validated(&[], 0).unwrap();
+	stream.append_raw_validated(&cat, 1).unwrap();
+	assert_eq!(stream.out().to_vec(), vec![0xc4, 0x83, b'c', b'a', b't']);
+}
+
+#[test]
+fn test_rlp_stream_append_flat() {
+	let values: Vec<u64> = (0..100).map(|i| i * 1_000).collect();
+
+	let mut expected = RlpStream::new_list(values.len() + 1);
+	expected.append(&"head");
+	for value in &values {
+		expected.append(value);
+	}
+
+	let mut stream = RlpStream::new_list(values.len() + 1);
+	stream.append(&"head").append_flat(values.iter().copied());
+	assert!(stream.is_finished());
+	assert_eq!(stream.out(), expected.out());
+
+	// Each value is an item of the current list, not of a nested one.
+	let mut stream = RlpStream::new_list(2);
+	stream.append_flat(vec![vec![1u8, 2], vec![]]);
+	assert_eq!(stream.out().to_vec(), vec![0xc4, 0x82, 0x01, 0x02, 0x80]);
+
+	let mut stream = RlpStream::new_list(0);
+	stream.append_flat(Vec::<u8>::new());
+	assert_eq!(stream.out().to_vec(), vec![0xc0]);
+}
+
 #[test]
 fn test_rlp_stream_unbounded_list() {
 	let mut stream = RlpStream::new();
@@ -754,3 +880,64 @@ fn test_list_at() {
 	let rlp2 = rlp.at(2).unwrap();
 	assert_eq!(rlp2.val_at::<u16>(2).unwrap(), 33338);
 }
+
+#[test]
+fn test_pretty_print() {
+	let mut stream = RlpStream::new_list(3);
+	stream.append(&"cat");
+	stream.begin_list(2).append(&1u8).append_empty_data();
+	stream.append(&vec![0u8; 60]);
+	let dump = rlp::pretty_print(&stream.out());
+
+	let lines: Vec<_> = dump.lines().collect();
+	assert_eq!(lines.len(), 6);
+	assert_eq!(lines[0], "list (3 items, 69 bytes)");
+	assert_eq!(lines[1], "  data (3 bytes): 0x636174");
+	assert_eq!(lines[2], "  list (2 items, 2 bytes)");
+	assert_eq!(lines[3], "    data (1 bytes): 0x01");
+	assert_eq!(lines[4], "    data (0 bytes): 0x");
+	assert!(lines[5].starts_with("  data (60 bytes): 0x0000"));
+}
+
+#[test]
+fn test_pretty_print_malformed() {
+	// The list header claims 4 bytes of payload, the last item is truncated.
+	let dump = rlp::pretty_print(&[0xc4, 0x83, b'c', b'a']);
+	assert_eq!(dump, "error: RlpIsTooShort\n");
+
+	let dump = rlp::pretty_print(&[0xc4, 0x01, 0x83, b'c', b'a']);
+	assert_eq!(dump, "list (1 items, 4 bytes)\n  data (1 bytes): 0x01\n  error: RlpIsTooShort\n");
+
+	let dump = rlp::pretty_print(&[0x01, 0x02]);
+	assert_eq!(dump, "data (1 bytes): 0x01\nerror: RlpIsTooBig\n");
+
+	assert_eq!(rlp::pretty_print(&[]), "error: RlpIsTooShort\n");
+}
+
+#[test]
+fn test_decode_list_iter() {
+	let list: Vec<u64> = (0..1000).collect();
+	let data = rlp::encode_list::<u64, u64>(&list);
+	let mut iter = rlp::decode_list_iter::<u64>(&data).unwrap();
+	assert_eq!(iter.next(), Some(Ok(0)));
+	assert_eq!(iter.next(), Some(Ok(1)));
+	let rest: Result<Vec<u64>, _> = iter.collect();
+	assert_eq!(rest.unwrap(), list[2..]);
+
+	assert_eq!(rlp::decode_list_iter::<u64>(&rlp::EMPTY_LIST_RLP).unwrap().count(), 0);
+	assert_eq!(rlp::decode_list_iter::<u64>(&[0x01]).err(), Some(DecoderError::RlpExpectedToBeList));
+	assert_eq!(rlp::decode_list_iter::<u64>(&[0xc2, 0x01]).err(), Some(DecoderError::RlpIsTooShort));
+}
+
+#[test]
+fn test_decode_list_iter_malformed() {
+	// The last item claims 3 bytes of payload, only 2 are left in the list.
+	let data = [0xc5, 0x01, 0x02, 0x83, b'c', b'a'];
+	let items: Vec<_> = rlp::decode_list_iter::<u8>(&data).unwrap().collect();
+	assert_eq!(items, vec![Ok(1), Ok(2), Err(DecoderError::RlpIsTooShort)]);
+
+	// An item of the wrong type does not prevent decoding the following ones.
+	let data = [0xc5, 0x01, 0x82, 0x01, 0x00, 0x03];
+	let items: Vec<_> = rlp::decode_list_iter::<u8>(&data).unwrap().collect();
+	assert_eq!(items, vec![Ok(1), Err(DecoderError::RlpIsTooBig), Ok(3)]);
+}
@@ -21,6 +21,10 @@ pub use core as core_;
 #[doc(hidden)]
 pub use hex;
 
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std as std_;
+
 #[cfg(feature = "quickcheck")]
 #[doc(hidden)]
 pub use quickcheck;
@@ -732,6 +732,11 @@ macro_rules! construct_uint {
 				r
 			}
 
+			/// Returns the number of ones in the binary representation of self.
+			pub fn count_ones(&self) -> u32 {
+				self.0.iter().map(|w| w.count_ones()).sum()
+			}
+
 			/// Return specific byte. Byte 0 is the least significant value (ie~ little endian).
 			///
 			/// # Panics
@@ -1738,9 +1743,58 @@ macro_rules! construct_uint {
 		// uints use 64 bit (8 byte) words
 		$crate::impl_quickcheck_arbitrary_for_uint!($name, ($n_words * 8));
 		$crate::impl_arbitrary_for_uint!($name, ($n_words * 8));
+		$crate::impl_std_for_uint!($name);
 	}
 }
 
+#[cfg(feature = "std")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_std_for_uint {
+	($name: ident) => {
+		impl $name {
+			/// Convert to a decimal string, the counterpart of `from_dec_str`.
+			pub fn to_dec_string(&self) -> $crate::std_::string::String {
+				self.fmt_radix(10)
+			}
+
+			/// Convert to a string in the given radix, using lowercase letters for digits above 9.
+			///
+			/// # Panics
+			///
+			/// Panics if `radix` is not in the range `2..=36`.
+			pub fn fmt_radix(&self, radix: u32) -> $crate::std_::string::String {
+				assert!((2..=36).contains(&radix), "radix must be in the range 2..=36");
+				if self.is_zero() {
+					return "0".into();
+				}
+
+				// radix 2 needs the most digits, one per bit
+				let mut digits = $crate::std_::vec::Vec::with_capacity(self.bits());
+				let mut current = *self;
+				while !current.is_zero() {
+					let (quotient, remainder) = current.div_mod_small(radix as u64);
+					let digit = $crate::core_::char::from_digit(remainder.low_u32(), radix)
+						.expect("remainder is less than radix; qed");
+					digits.push(digit as u8);
+					current = quotient;
+				}
+				digits.reverse();
+
+				// sequence of `'0'..'9' 'a'..'z'` chars is guaranteed to be a valid UTF8 string
+				$crate::std_::string::String::from_utf8(digits).expect("digits are ASCII; qed")
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "std"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_std_for_uint {
+	($name: ident) => {};
+}
+
 #[cfg(feature = "quickcheck")]
 #[macro_export]
 #[doc(hidden)]
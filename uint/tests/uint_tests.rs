@@ -467,6 +467,34 @@ pub fn display_u512() {
 	assert_eq!(format!("{:?}", value), expected);
 }
 
+#[test]
+fn to_dec_string_roundtrip() {
+	for value in [U256::zero(), U256::one(), U256::from(1234567890u64), U256::exp10(50), U256::MAX] {
+		let dec = value.to_dec_string();
+		assert_eq!(dec, format!("{}", value));
+		assert_eq!(U256::from_dec_str(&dec).unwrap(), value);
+	}
+	assert_eq!(U512::MAX.to_dec_string(), format!("{}", U512::MAX));
+}
+
+#[test]
+fn fmt_radix() {
+	let value = U256::from_str("deadbeef").unwrap();
+	assert_eq!(value.fmt_radix(10), "3735928559");
+	assert_eq!(value.fmt_radix(16), "deadbeef");
+	assert_eq!(value.fmt_radix(2), "11011110101011011011111011101111");
+	assert_eq!(value.fmt_radix(36), "1ps9wxb");
+	assert_eq!(U256::zero().fmt_radix(7), "0");
+	assert_eq!(U256::MAX.fmt_radix(16), format!("{:x}", U256::MAX));
+	assert_eq!(U256::MAX.fmt_radix(2), "1".repeat(256));
+}
+
+#[test]
+#[should_panic]
+fn fmt_radix_unsupported() {
+	U256::one().fmt_radix(37);
+}
+
 #[test]
 fn uint256_overflowing_pow() {
 	assert_eq!(
@@ -476,6 +504,15 @@ fn uint256_overflowing_pow() {
 	assert_eq!(U256::from(2).overflowing_pow(U256::from(0x100)), (U256::zero(), true));
 }
 
+#[test]
+fn uint512_checked_pow() {
+	assert_eq!(U512::from(3).checked_pow(U512::from(5)), Some(U512::from(243)));
+	assert_eq!(U512::from(2).checked_pow(U512::from(0x100)), Some(U512::one() << 0x100));
+	assert_eq!(U512::from(2).checked_pow(U512::from(0x1ff)), Some(U512::one() << 0x1ff));
+	assert_eq!(U512::from(2).checked_pow(U512::from(0x200)), None);
+	assert_eq!(U512::from(2).overflowing_pow(U512::from(0x200)), (U512::zero(), true));
+}
+
 #[test]
 fn uint256_mul1() {
 	assert_eq!(U256::from(1u64) * U256::from(10u64), U256::from(10u64));
@@ -885,6 +922,48 @@ fn u512_div() {
 	assert_eq!((x, y), (q, r));
 }
 
+#[test]
+fn div_mod_matches_div_and_rem() {
+	// xorshift64, so that the pairs are reproducible.
+	let mut state = 0x2545_f491_4f6c_dd1du64;
+	let mut next = || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state
+	};
+
+	for _ in 0..1000 {
+		let mut words = [0u64; 16];
+		words.iter_mut().for_each(|w| *w = next());
+		let shifts = next();
+
+		let a = U512(words[..8].try_into().unwrap()) >> (shifts as usize % 512);
+		let b = U512(words[8..].try_into().unwrap()) >> ((shifts >> 16) as usize % 512);
+		if !b.is_zero() {
+			assert_eq!(a.div_mod(b), (a / b, a % b), "{} / {}", a, b);
+		}
+
+		let a = U256(words[..4].try_into().unwrap()) >> ((shifts >> 32) as usize % 256);
+		let b = U256(words[8..12].try_into().unwrap()) >> ((shifts >> 48) as usize % 256);
+		if !b.is_zero() {
+			assert_eq!(a.div_mod(b), (a / b, a % b), "{} / {}", a, b);
+		}
+	}
+
+	let a = U256::from(7);
+	assert_eq!(a.div_mod(U256::MAX), (U256::zero(), a));
+	assert_eq!(U256::MAX.div_mod(U256::MAX), (U256::one(), U256::zero()));
+	assert_eq!(U256::MAX.div_mod(U256::one()), (U256::MAX, U256::zero()));
+	assert_eq!(U256::zero().div_mod(a), (U256::zero(), U256::zero()));
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn div_mod_by_zero_panics() {
+	let _ = U256::from(1).div_mod(U256::zero());
+}
+
 #[test]
 fn big_endian() {
 	let source = U256([1, 0, 0, 0]);
@@ -1150,6 +1229,22 @@ fn trailing_zeros() {
 	assert_eq!(U256::from("0000000000000000000000000000000000000000000000000000000000000000").trailing_zeros(), 256);
 }
 
+#[test]
+fn count_ones() {
+	assert_eq!(U256::zero().count_ones(), 0);
+	assert_eq!(U256::one().count_ones(), 1);
+	assert_eq!(U256::MAX.count_ones(), 256);
+	assert_eq!(U512::MAX.count_ones(), 512);
+	// Bits set on both sides of every limb boundary.
+	let value = U256([1 << 63 | 1, 1 << 63 | 1, 1 << 63 | 1, 1 << 63 | 1]);
+	assert_eq!(value.count_ones(), 8);
+	assert_eq!(value.leading_zeros(), 0);
+	assert_eq!(value.trailing_zeros(), 0);
+	assert_eq!(U256([0, 1 << 63, 1, 0]).count_ones(), 2);
+	assert_eq!(U256([0, 1 << 63, 1, 0]).leading_zeros(), 127);
+	assert_eq!(U256([0, 1 << 63, 1, 0]).trailing_zeros(), 127);
+}
+
 #[test]
 fn bit_assign() {
 	fn check(a: U256, b: U256) {